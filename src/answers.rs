@@ -0,0 +1,201 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::{fs, io};
+
+use crate::report;
+
+/// Name of the answers file in the directory of each year, e.g. `src/2022/answers.toml`.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Expected answers for the real puzzle inputs, stored as
+///
+/// ```toml
+/// [day01]
+/// part1 = "24000"
+/// part2 = "41000"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn path(year: u16) -> PathBuf {
+        crate::year_dir(year).join(ANSWERS_FILE)
+    }
+
+    /// Loads the answers file. A missing file is treated as empty.
+    pub fn load(year: u16) -> io::Result<Self> {
+        match fs::read_to_string(Self::path(year)) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, year: u16) -> io::Result<()> {
+        fs::write(Self::path(year), self.to_toml())
+    }
+
+    /// Parses the subset of TOML written by [`Answers::to_toml`].
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let answers = parse_parts(ANSWERS_FILE, contents)?
+            .into_iter()
+            .map(|(key, (_, value))| (key, value))
+            .collect();
+
+        Ok(Answers { answers })
+    }
+
+    pub fn to_toml(&self) -> String {
+        write_parts(
+            self.answers
+                .iter()
+                .map(|(&key, answer)| (key, report::json_string(answer))),
+        )
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+}
+
+/// Values of a file read by [`parse_parts`] by `(day, part)`, with the line they were read from.
+pub(crate) type Parts = BTreeMap<(u8, u8), (usize, String)>;
+
+/// Parses `[dayNN]` sections with `part1` / `part2` keys, as used by `answers.toml` and `baseline.toml`.
+/// Values may be quoted, with escapes as in TOML basic strings. Returns the line number and
+/// unquoted value of every part.
+pub(crate) fn parse_parts(file: &str, contents: &str) -> Result<Parts, String> {
+    let mut parts = BTreeMap::new();
+    let mut day = None;
+
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        let invalid = || format!("{}:{}: invalid line \"{}\"", file, i + 1, line);
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(section) = line.strip_prefix("[day").and_then(|l| l.strip_suffix(']')) {
+            day = Some(section.parse::<u8>().map_err(|_| invalid())?);
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+        let part = match key.trim() {
+            "part1" => 1,
+            "part2" => 2,
+            _ => return Err(invalid()),
+        };
+        let value = value.trim();
+        let value = if value.starts_with('"') {
+            report::unquote(value).ok_or_else(invalid)?
+        } else {
+            value.to_string()
+        };
+
+        parts.insert((day.ok_or_else(invalid)?, part), (i + 1, value));
+    }
+
+    Ok(parts)
+}
+
+/// Writes values sorted by day and part in the format read by [`parse_parts`].
+/// Values are written as given and must be quoted by the caller if needed.
+pub(crate) fn write_parts(values: impl IntoIterator<Item = ((u8, u8), String)>) -> String {
+    let mut out = String::new();
+    let mut current_day = None;
+
+    for ((day, part), value) in values {
+        if current_day != Some(day) {
+            if current_day.is_some() {
+                out.push('\n');
+            }
+            out.push_str(&format!("[day{:02}]\n", day));
+            current_day = Some(day);
+        }
+        out.push_str(&format!("part{} = {}\n", part, value));
+    }
+
+    out
+}
+
+/// Result of comparing an answer against the recorded one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    /// The answer matches the recorded answer.
+    Pass,
+    /// The answer differs from the recorded answer, or the part no longer produces one.
+    Regression,
+    /// No answer is recorded for this part.
+    Unrecorded,
+}
+
+pub fn check(answer: Option<&str>, expected: Option<&str>) -> Check {
+    match (answer, expected) {
+        (_, None) => Check::Unrecorded,
+        (Some(answer), Some(expected)) if answer == expected => Check::Pass,
+        _ => Check::Regression,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(5, 2, "MCD");
+        answers.set(1, 1, "24000");
+        answers.set(5, 1, "CMZ");
+
+        let toml = answers.to_toml();
+        assert_eq!(
+            toml,
+            "[day01]\npart1 = \"24000\"\n\n[day05]\npart1 = \"CMZ\"\npart2 = \"MCD\"\n"
+        );
+        assert_eq!(Answers::parse(&toml), Ok(answers));
+    }
+
+    #[test]
+    fn test_toml_roundtrip_escapes() {
+        let mut answers = Answers::default();
+        answers.set(10, 2, "#..#\n#..#\n####");
+        answers.set(13, 1, "say \"hi\" \\o/");
+
+        let toml = answers.to_toml();
+        assert!(toml.contains("part2 = \"#..#\\n#..#\\n####\"\n"));
+        assert!(toml.contains("part1 = \"say \\\"hi\\\" \\\\o/\"\n"));
+        assert_eq!(Answers::parse(&toml), Ok(answers));
+        assert!(Answers::parse("[day01]\npart1 = \"a\" b\"").is_err());
+    }
+
+    #[test]
+    fn test_parse_unquoted_and_comments() {
+        let answers = Answers::parse("# real inputs\n[day07]\npart2 = 24933642\n").unwrap();
+        assert_eq!(answers.get(7, 2), Some("24933642"));
+        assert_eq!(answers.get(7, 1), None);
+        assert!(Answers::parse("part1 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("1"), Some("1")), Check::Pass);
+        assert_eq!(check(Some("1"), Some("2")), Check::Regression);
+        assert_eq!(check(None, Some("2")), Check::Regression);
+        assert_eq!(check(Some("1"), None), Check::Unrecorded);
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{fs, io};
+
+use crate::answers::{parse_parts, write_parts};
+
+/// Name of the baseline file in the directory of each year, e.g. `src/2022/baseline.toml`.
+pub const BASELINE_FILE: &str = "baseline.toml";
+
+/// Slowdown in percent above which a part is flagged as slower than its baseline.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Timings of earlier runs in nanoseconds, stored as
+///
+/// ```toml
+/// [day01]
+/// part1 = 37030
+/// part2 = 33180
+/// ```
+///
+/// Part timings never include parsing the input, which is timed on its own, so that timings
+/// saved in-process and with `--subprocess` can be compared with each other.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    timings: BTreeMap<(u8, u8), Duration>,
+}
+
+impl Baseline {
+    pub fn path(year: u16) -> PathBuf {
+        crate::year_dir(year).join(BASELINE_FILE)
+    }
+
+    /// Loads the baseline file. A missing file is treated as empty.
+    pub fn load(year: u16) -> io::Result<Self> {
+        match fs::read_to_string(Self::path(year)) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, year: u16) -> io::Result<()> {
+        fs::write(Self::path(year), self.to_toml())
+    }
+
+    /// Parses the subset of TOML written by [`Baseline::to_toml`].
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut baseline = Baseline::default();
+
+        for ((day, part), (line, value)) in parse_parts(BASELINE_FILE, contents)? {
+            let nanos = value.parse().map_err(|_| {
+                format!("{}:{}: invalid duration \"{}\"", BASELINE_FILE, line, value)
+            })?;
+            baseline.set(day, part, Duration::from_nanos(nanos));
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn to_toml(&self) -> String {
+        write_parts(
+            self.timings
+                .iter()
+                .map(|(&key, duration)| (key, duration.as_nanos().to_string())),
+        )
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        self.timings.get(&(day, part)).copied()
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, duration: Duration) {
+        self.timings.insert((day, part), duration);
+    }
+}
+
+/// Change from `baseline` to `current` in percent, positive if `current` is slower.
+pub fn change(current: Duration, baseline: Duration) -> Option<f64> {
+    if baseline.is_zero() {
+        return None;
+    }
+    Some((current.as_secs_f64() / baseline.as_secs_f64() - 1_f64) * 100_f64)
+}
+
+/// Parses a threshold in percent, e.g. `10` or `2.5`.
+pub fn parse_threshold(percent: &str) -> Result<f64, String> {
+    match percent.trim().trim_end_matches('%').parse::<f64>() {
+        Ok(percent) if percent.is_finite() && percent >= 0_f64 => Ok(percent),
+        _ => Err(format!(
+            "invalid threshold \"{}\", expected a percentage",
+            percent
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline.set(8, 2, Duration::from_nanos(181200));
+        baseline.set(1, 1, Duration::from_nanos(37030));
+
+        let toml = baseline.to_toml();
+        assert_eq!(toml, "[day01]\npart1 = 37030\n\n[day08]\npart2 = 181200\n");
+        assert_eq!(Baseline::parse(&toml), Ok(baseline));
+        assert_eq!(
+            Baseline::parse("[day01]\npart1 = \"fast\""),
+            Err("baseline.toml:2: invalid duration \"fast\"".into())
+        );
+    }
+
+    #[test]
+    fn test_change() {
+        let ms = Duration::from_millis;
+        assert_eq!(change(ms(15), ms(10)), Some(50.0));
+        assert_eq!(change(ms(5), ms(10)), Some(-50.0));
+        assert_eq!(change(ms(5), Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_parse_threshold() {
+        assert_eq!(parse_threshold("2.5"), Ok(2.5));
+        assert_eq!(parse_threshold("10%"), Ok(10.0));
+        assert!(parse_threshold("-1").is_err());
+    }
+}
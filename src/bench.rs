@@ -0,0 +1,240 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Upper bound for budget-limited runs, keeps the sample buffer reasonably small.
+const MAX_ITERATIONS: u32 = 100_000;
+
+/// Runs of `--profile` without `--iterations`.
+pub const DEFAULT_PROFILE_ITERATIONS: u32 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before measuring.
+    pub warmup: u32,
+    /// Exact number of timed runs. If `None`, runs until `budget` is used up.
+    pub iterations: Option<u32>,
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            iterations: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+impl BenchOptions {
+    /// Takes `--bench [--warmup <n>] [--iterations <n>] [--budget <ms>]` out of `args`.
+    /// Returns `None` if `--bench` is not present, and an error if one of the values is invalid.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        if !args.contains("--bench") {
+            return Ok(None);
+        }
+
+        let defaults = BenchOptions::default();
+        let budget_ms: Option<u64> = args.opt_value_from_str("--budget")?;
+
+        Ok(Some(BenchOptions {
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(defaults.warmup),
+            iterations: args.opt_value_from_str("--iterations")?,
+            budget: budget_ms.map_or(defaults.budget, Duration::from_millis),
+        }))
+    }
+}
+
+impl BenchOptions {
+    /// The arguments that [`BenchOptions::from_args`] reads these options from.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--bench".into(),
+            "--warmup".into(),
+            self.warmup.to_string(),
+            "--budget".into(),
+            self.budget.as_millis().to_string(),
+        ];
+        if let Some(iterations) = self.iterations {
+            args.extend(["--iterations".into(), iterations.to_string()]);
+        }
+        args
+    }
+}
+
+/// Loops a single part, so that profilers like `perf` collect enough samples of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfileOptions {
+    pub part: u8,
+    pub iterations: u32,
+}
+
+impl ProfileOptions {
+    /// Takes `--profile <part> [--iterations <n>]` out of `args`.
+    /// Returns `None` if `--profile` is not present, and an error if one of the values is invalid.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let Some(part) = args.opt_value_from_fn("--profile", crate::runner::parse_part)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(ProfileOptions {
+            part,
+            iterations: args
+                .opt_value_from_str("--iterations")?
+                .unwrap_or(DEFAULT_PROFILE_ITERATIONS),
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub iterations: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let nanos = samples.iter().map(|s| s.as_nanos() as f64);
+        let mean = nanos.clone().sum::<f64>() / n as f64;
+        let variance = nanos.map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        BenchStats {
+            iterations: n as u32,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Runs `func` repeatedly according to `options` and collects timing statistics.
+pub fn run<I: ?Sized, T>(options: BenchOptions, func: impl Fn(&I) -> T, input: &I) -> BenchStats {
+    for _ in 0..options.warmup {
+        black_box(func(black_box(input)));
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+
+    loop {
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        samples.push(timer.elapsed());
+
+        let done = match options.iterations {
+            Some(iterations) => samples.len() as u32 >= iterations,
+            None => start.elapsed() >= options.budget || samples.len() as u32 >= MAX_ITERATIONS,
+        };
+        if done {
+            break;
+        }
+    }
+
+    BenchStats::from_samples(&mut samples)
+}
+
+/// Runs `func` `options.iterations` times without timing the single runs, and returns the total time.
+pub fn profile<I: ?Sized, T>(
+    options: ProfileOptions,
+    func: impl Fn(&I) -> T,
+    input: &I,
+) -> Duration {
+    let start = Instant::now();
+    for _ in 0..options.iterations {
+        black_box(func(black_box(input)));
+    }
+    start.elapsed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = BenchStats::from_samples(&mut samples);
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(2));
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.stddev, Duration::from_nanos(1));
+    }
+
+    #[test]
+    fn test_bench_options_from_args() {
+        let args = |args: &[&str]| {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            BenchOptions::from_args(&mut args)
+        };
+        assert_eq!(args(&["--iterations", "5"]).unwrap(), None);
+        assert_eq!(
+            args(&["--bench", "--iterations", "5"]).unwrap(),
+            Some(BenchOptions {
+                iterations: Some(5),
+                ..BenchOptions::default()
+            })
+        );
+        assert!(args(&["--bench", "--budget", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_profile_options_from_args() {
+        let args = |args: &[&str]| {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            ProfileOptions::from_args(&mut args)
+        };
+        assert_eq!(args(&[]).unwrap(), None);
+        assert_eq!(
+            args(&["--profile", "2"]).unwrap(),
+            Some(ProfileOptions {
+                part: 2,
+                iterations: DEFAULT_PROFILE_ITERATIONS
+            })
+        );
+        assert!(args(&["--profile", "3"]).is_err());
+        assert!(args(&["--profile", "1", "--iterations", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_run_exact_iterations() {
+        let options = BenchOptions {
+            warmup: 0,
+            iterations: Some(7),
+            ..BenchOptions::default()
+        };
+        let stats = run(options, |input| input.len(), "abc");
+        assert_eq!(stats.iterations, 7);
+    }
+
+    #[test]
+    fn test_profile_iterations() {
+        let calls = std::cell::Cell::new(0);
+        let options = ProfileOptions {
+            part: 1,
+            iterations: 42,
+        };
+        profile(options, |_: &str| calls.set(calls.get() + 1), "abc");
+        assert_eq!(calls.get(), 42);
+    }
+}
@@ -0,0 +1,33 @@
+use advent_of_code::days::y2022::day01::Day01;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 1);
+    advent_of_code::solve!(Day01, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::days::y2022::day01::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 1);
+        assert_eq!(part_one(&input), Ok(Some(24000)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 1);
+        assert_eq!(part_two(&input), Ok(Some(41000)));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_one("1000\nlots\n\n"),
+            Err(
+                advent_of_code::days::y2022::day01::ParseError::InvalidCalories("lots".to_string())
+            )
+        );
+    }
+}
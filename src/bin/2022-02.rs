@@ -0,0 +1,31 @@
+use advent_of_code::days::y2022::day02::Day02;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 2);
+    advent_of_code::solve!(Day02, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::days::y2022::day02::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 2);
+        assert_eq!(part_one(&input), Ok(Some(15)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 2);
+        assert_eq!(part_two(&input), Ok(Some(12)));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_one("A Y\nB Q\n"),
+            Err(advent_of_code::days::y2022::day02::ParseError::UnexpectedChar('Q'))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use advent_of_code::days::y2022::day03::Day03;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 3);
+    advent_of_code::solve!(Day03, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::days::y2022::day03::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 3);
+        assert_eq!(part_one(&input), Ok(Some(157)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 3);
+        assert_eq!(part_two(&input), Ok(Some(70)));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_one("vJrwpWtwJgWr\nab1c\n"),
+            Err(advent_of_code::days::y2022::day03::ParseError::UnexpectedChar('1'))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use advent_of_code::days::y2022::day04::Day04;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 4);
+    advent_of_code::solve!(Day04, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::days::y2022::day04::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 4);
+        assert_eq!(part_one(&input), Ok(Some(2)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 4);
+        assert_eq!(part_two(&input), Ok(Some(4)));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_one("2-4,6-8\n5-3,1-2\n"),
+            Err(advent_of_code::days::y2022::day04::ParseError::InvalidRange("5-3".to_string()))
+        );
+    }
+}
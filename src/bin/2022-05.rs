@@ -0,0 +1,31 @@
+use advent_of_code::days::y2022::day05::Day05;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 5);
+    advent_of_code::solve!(Day05, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::days::y2022::day05::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_one(&input), Ok(Some("CMZ".to_string())));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_two(&input), Ok(Some("MCD".to_string())));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_one("[A]\n 1 \n\nmove 1 from 1 to 2\n"),
+            Err(advent_of_code::days::y2022::day05::ParseError::UnknownStack(2))
+        );
+    }
+}
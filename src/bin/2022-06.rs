@@ -0,0 +1,31 @@
+use advent_of_code::days::y2022::day06::Day06;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 6);
+    advent_of_code::solve!(Day06, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::days::y2022::day06::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 6);
+        assert_eq!(part_one(&input), Ok(Some(7)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 6);
+        assert_eq!(part_two(&input), Ok(Some(19)));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_one("abcd efgh\n"),
+            Err(advent_of_code::days::y2022::day06::ParseError::UnexpectedChar(' '))
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use advent_of_code::days::y2022::day07::Day07;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 7);
+    advent_of_code::solve!(Day07, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::days::y2022::day07::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 7);
+        assert_eq!(part_one(&input), Ok(Some(95437)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 7);
+        assert_eq!(part_two(&input), Ok(Some(24933642)));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_two("$ cd /\n$ ls\nlarge b.txt\n"),
+            Err(
+                advent_of_code::days::y2022::day07::ParseError::MalformedFile(
+                    "large b.txt".to_string()
+                )
+            )
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use advent_of_code::days::y2022::day08::Day08;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 8);
+    advent_of_code::solve!(Day08, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::days::y2022::day08::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_one(&input), Ok(Some(21)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_two(&input), Ok(Some(8)));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_one("303\n25\n"),
+            Err(advent_of_code::days::y2022::day08::ParseError::RaggedRow(
+                "25".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use advent_of_code::days::y2022::day09::Day09;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 9);
+    advent_of_code::solve!(Day09, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::days::y2022::day09::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 9);
+        assert_eq!(part_one(&input), Ok(Some(88)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 9);
+        assert_eq!(part_two(&input), Ok(Some(36)));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            part_one("R 4\nX 2\n"),
+            Err(
+                advent_of_code::days::y2022::day09::ParseError::UnexpectedDirection(
+                    "X".to_string()
+                )
+            )
+        );
+    }
+}
@@ -0,0 +1,147 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::days::{self, ReferenceDay};
+use advent_of_code::differential::{Mismatch, Options, Outcome, DEFAULT_CASES};
+use advent_of_code::runner;
+use advent_of_code::style::{self, bold, italic};
+use std::panic;
+use std::process;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+struct Args {
+    /// Day to test. Tests all days with a reference implementation if `None`.
+    day: Option<u8>,
+    year: Option<u16>,
+    cases: u32,
+    seed: Option<u64>,
+    part: Option<u8>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    style::remove_color_arg(&mut args)?;
+    Ok(Args {
+        year: args.opt_value_from_fn(["-y", "--year"], runner::parse_year)?,
+        cases: args.opt_value_from_str("--cases")?.unwrap_or(DEFAULT_CASES),
+        seed: args.opt_value_from_str("--seed")?,
+        part: args.opt_value_from_fn("--part", runner::parse_part)?,
+        day: args.opt_free_from_str()?,
+    })
+}
+
+fn describe(outcome: &Outcome) -> String {
+    match outcome {
+        Ok(Some(answer)) => answer.clone(),
+        Ok(None) => "not solved".into(),
+        Err(error) => error.clone(),
+    }
+}
+
+fn size(input: &str) -> String {
+    match input.lines().count() {
+        1 => format!("{} characters", input.trim_end().chars().count()),
+        lines => format!("{} lines", lines),
+    }
+}
+
+fn print_mismatch(reference: &ReferenceDay, mismatch: &Mismatch) {
+    println!(
+        "{} Part {} differs from the reference on the input with seed {}.",
+        bold("✗"),
+        mismatch.part,
+        mismatch.seed
+    );
+    println!("  Solution:  {}", describe(&mismatch.solution));
+    println!("  Reference: {}", describe(&mismatch.reference));
+    println!(
+        "{}",
+        italic(format!(
+            "Minimized input ({}, generated with {}):",
+            size(&mismatch.input),
+            size(&mismatch.generated)
+        ))
+    );
+    println!("{}", mismatch.input);
+    println!(
+        "Reproduce with `cargo difftest {} --year {} --seed {} --cases 1`.",
+        reference.day, reference.year, mismatch.seed
+    );
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let Some(year) = args.year.or_else(advent_of_code::default_year) else {
+        eprintln!("Need to specify a year with `--year` or `AOC_YEAR`. example: `cargo difftest 4 --year 2022`");
+        process::exit(1);
+    };
+
+    let references: Vec<&ReferenceDay> = match args.day {
+        Some(day) => match days::get_reference(year, day) {
+            Some(reference) => vec![reference],
+            None => {
+                eprintln!(
+                    "Day {} of {} has no reference implementation. Register one with `register_references!` in `src/days/mod.rs`.",
+                    day, year
+                );
+                process::exit(1);
+            }
+        },
+        None => days::REFERENCES
+            .iter()
+            .filter(|reference| reference.year == year)
+            .collect(),
+    };
+
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64)
+    });
+    let options = Options {
+        cases: args.cases,
+        seed,
+        part: args.part,
+    };
+
+    // panics are reported as part of a mismatch, and minimizing can cause a lot of them.
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut mismatches = 0;
+    for reference in references {
+        println!(
+            "{} {}",
+            bold(format!("Day {:02}:", reference.day)),
+            italic(format!(
+                "{} random inputs from seed {}",
+                options.cases, seed
+            ))
+        );
+
+        let timer = Instant::now();
+        match reference.test(options) {
+            Ok(cases) => println!(
+                "{} {} inputs agree with the reference. {}",
+                bold("✓"),
+                cases,
+                italic(format!("(elapsed: {:.2?})", timer.elapsed()))
+            ),
+            Err(mismatch) => {
+                print_mismatch(reference, &mismatch);
+                mismatches += 1;
+            }
+        }
+    }
+
+    if mismatches > 0 {
+        process::exit(1);
+    }
+}
@@ -2,18 +2,20 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::style;
 use std::io::Write;
 use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
+use std::{env, env::temp_dir, io, process::Command};
 use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: Option<u16>,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
+    style::remove_color_arg(&mut args)?;
     Ok(Args {
         day: args.free_from_str()?,
         year: args.opt_value_from_str(["-y", "--year"])?,
@@ -47,8 +49,20 @@ fn main() {
         }
     };
 
+    let Some(year) = args.year.or_else(advent_of_code::default_year) else {
+        eprintln!("Need to specify a year with `--year` or `AOC_YEAR`. example: `cargo download 7 --year 2022`");
+        exit_with_status(1, &tmp_file_path);
+    };
+
+    // the input path is relative to the crate root.
+    if let Err(e) = env::set_current_dir(advent_of_code::crate_root()) {
+        eprintln!("could not change to the crate root: {}", e);
+        exit_with_status(1, &tmp_file_path);
+    }
+
     let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let input_dir = format!("src/{}/inputs", year);
+    let input_path = format!("{}/{}.txt", input_dir, day_padded);
 
     // check if aoc binary exists and is callable.
     if Command::new("aoc").arg("-V").output().is_err() {
@@ -56,20 +70,15 @@ fn main() {
         exit_with_status(1, &tmp_file_path);
     }
 
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec![
+    let cmd_args = vec![
+        "--year".into(),
+        year.to_string(),
         "--input-file".into(),
         tmp_file_path.to_string_lossy().to_string(),
         "--day".into(),
         args.day.to_string(),
         "download".into(),
-    ]);
+    ];
 
     println!("Downloading input with >aoc {}", cmd_args.join(" "));
 
@@ -91,10 +100,18 @@ fn main() {
         }
     }
 
+    if let Err(e) = fs::create_dir_all(&input_dir) {
+        eprintln!("could not create input directory: {}", e);
+        exit_with_status(1, &tmp_file_path);
+    }
+
     match fs::copy(&tmp_file_path, &input_path) {
         Ok(_) => {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+            println!(
+                "🎄 Successfully wrote input to {}.",
+                style::bold(format!("\"{}\"", &input_path))
+            );
             exit_with_status(0, &tmp_file_path);
         }
         Err(e) => {
@@ -0,0 +1,208 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::report::{Format, FORMAT_ENV};
+use advent_of_code::runner::{self, DayRun, DayStatus, TestRun};
+use advent_of_code::style::{self, bold};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::thread;
+use std::time::{Duration, SystemTime};
+use std::{env, fs};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Time without further changes before a run starts, so that a burst of saves causes a single run.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+    /// Run the solution with `--release`. Tests always use the dev profile.
+    release: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    style::remove_color_arg(&mut args)?;
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        release: args.contains("--release"),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Files of a day that trigger a run when they change, relative to the crate root.
+fn watched_files(year: u16, day: u8) -> Vec<PathBuf> {
+    vec![
+        PathBuf::from(format!(
+            "src/bin/{}.rs",
+            advent_of_code::bin_name(year, day)
+        )),
+        PathBuf::from(format!("src/days/y{}/day{:02}.rs", year, day)),
+        PathBuf::from("src/helpers.rs"),
+        PathBuf::from(format!("src/{}/examples/{:02}.txt", year, day)),
+        PathBuf::from(format!("src/{}/inputs/{:02}.txt", year, day)),
+    ]
+}
+
+/// Modification times of the watched files, `None` for files that do not exist.
+fn snapshot(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Blocks until a watched file changes and no further changes happen for [`DEBOUNCE`].
+/// Returns the files that changed.
+fn wait_for_change<'a>(files: &'a [PathBuf], last: &mut Vec<Option<SystemTime>>) -> Vec<&'a Path> {
+    let mut current = snapshot(files);
+    while current == *last {
+        thread::sleep(POLL_INTERVAL);
+        current = snapshot(files);
+    }
+
+    loop {
+        thread::sleep(DEBOUNCE);
+        let settled = snapshot(files);
+        if settled == current {
+            break;
+        }
+        current = settled;
+    }
+
+    let changed = files
+        .iter()
+        .zip(last.iter().zip(&current))
+        .filter(|(_, (before, after))| before != after)
+        .map(|(file, _)| file.as_path())
+        .collect();
+    *last = current;
+    changed
+}
+
+/// Runs the unit tests of a day and prints a summary. Returns `false` if they could not be built.
+fn run_tests(year: u16, day: u8) -> bool {
+    let tests = match runner::run_tests(year, day) {
+        Ok(tests) => tests,
+        Err(e) => {
+            eprintln!("Failed to run cargo test: {}", e);
+            return false;
+        }
+    };
+
+    match tests {
+        TestRun::Finished {
+            passed, failed: 0, ..
+        } => {
+            println!("{} ✓ {} passed", bold("Tests:"), passed)
+        }
+        TestRun::Finished {
+            passed,
+            failed,
+            failures,
+        } => {
+            println!("{} ✗ {} failed, {} passed", bold("Tests:"), failed, passed);
+            for (name, message) in failures {
+                match message {
+                    Some(message) => println!("  {}: {}", name, message.replace('\n', "\n    ")),
+                    None => println!("  {}", name),
+                }
+            }
+        }
+        TestRun::BuildFailed(error) => {
+            println!("{} {}", bold("Build failed:"), error);
+            return false;
+        }
+        TestRun::NoResults => println!("{} no results", bold("Tests:")),
+    }
+    true
+}
+
+fn run_solution(year: u16, day: u8, release: bool) {
+    let mut args = vec!["run", "--bin"];
+    let bin_name = advent_of_code::bin_name(year, day);
+    args.push(&bin_name);
+    if release {
+        args.push("--release");
+    }
+
+    let output = match Command::new("cargo")
+        .args(args)
+        .env(FORMAT_ENV, "jsonl")
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo run: {}", e);
+            return;
+        }
+    };
+
+    print_run(&runner::parse_output(year, day, output));
+}
+
+fn print_run(run: &DayRun) {
+    for report in &run.reports {
+        print!("{} ", bold(format!("Part {}:", report.part)));
+        report.print(Format::Text);
+        report.print_error();
+    }
+
+    match &run.status {
+        DayStatus::Ran => {}
+        DayStatus::NotSolved => println!("Not solved."),
+        DayStatus::MissingInput(error) => println!("{} {}", bold("Input missing:"), error),
+        DayStatus::Unstructured(output) => println!("{}", output.trim()),
+        DayStatus::TimedOut(timeout) => println!("Timed out after {:.2?}.", timeout),
+        DayStatus::BuildFailed(error) => println!("{} {}", bold("Build failed:"), error),
+        DayStatus::Panicked(message) => println!("{} {}", bold("Panicked:"), message),
+        DayStatus::Crashed(status) => println!("{} {}", bold("Crashed:"), status),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo iterate 7`");
+            process::exit(1);
+        }
+    };
+
+    let Some(year) = args.year.or_else(advent_of_code::default_year) else {
+        eprintln!("Need to specify a year with `--year` or `AOC_YEAR`. example: `cargo iterate 7 --year 2022`");
+        process::exit(1);
+    };
+
+    // watched paths are relative to the crate root.
+    if let Err(e) = env::set_current_dir(advent_of_code::crate_root()) {
+        eprintln!("Failed to change to the crate root: {}", e);
+        process::exit(1);
+    }
+
+    let files = watched_files(year, args.day);
+    let mut last = snapshot(&files);
+
+    println!(
+        "👀 Watching day {} of {}. Press Ctrl+C to stop.",
+        args.day, year
+    );
+    let mut changed: Vec<&Path> = vec![];
+
+    loop {
+        println!("----------");
+        for file in &changed {
+            println!("Changed {}", style::italic(file.display()));
+        }
+
+        if run_tests(year, args.day) {
+            run_solution(year, args.day, args.release);
+        }
+
+        changed = wait_for_change(&files, &mut last);
+    }
+}
@@ -2,47 +2,94 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::style;
 use std::{
-    fs::{File, OpenOptions},
+    env,
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const SOLUTION_TEMPLATE: &str = r###"use std::convert::Infallible;
+
+use crate::solution::Solution;
+
+pub struct DayDAY_PADDED;
+
+impl Solution for DayDAY_PADDED {
+    type Parsed = String;
+    type Error = Infallible;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Parsed) -> Option<u32> {
+        None
+    }
+
+    fn part_two(input: &Self::Parsed) -> Option<u32> {
+        None
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let Ok(parsed) = DayDAY_PADDED::parse(input);
+    DayDAY_PADDED::part_one(&parsed)
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    None
+    let Ok(parsed) = DayDAY_PADDED::parse(input);
+    DayDAY_PADDED::part_two(&parsed)
 }
+"###;
+
+const MODULE_TEMPLATE: &str = r###"use advent_of_code::days::yYEAR::dayDAY_PADDED::DayDAY_PADDED;
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_input(YEAR, DAY);
+    advent_of_code::solve!(DayDAY_PADDED, input);
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use advent_of_code::days::yYEAR::dayDAY_PADDED::{part_one, part_two};
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_two(&input), None);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+const YEAR_MODULE_TEMPLATE: &str = r###"/*
+ * Solutions for YEAR. `cargo scaffold` adds new days to this list.
+ */
+"###;
+
+const REGISTRY_PATH: &str = "src/days/mod.rs";
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    style::remove_color_arg(&mut args)?;
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
+    })
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
@@ -50,23 +97,137 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Inserts `entry` after the last line that starts with `prefix`, or at the end if there is none.
+fn insert_after_last(contents: &str, prefix: &str, entry: &str) -> String {
+    let end = match contents.rfind(prefix) {
+        Some(last) => {
+            last + contents[last..]
+                .find('\n')
+                .map_or(contents.len() - last, |i| i + 1)
+        }
+        None => contents.len(),
+    };
+    format!("{}{}{}", &contents[..end], entry, &contents[end..])
+}
+
+/// Declares the solution module, creating the module of its year if needed, and adds
+/// `(<year>, <day>) => y<year>::day<DD>::Day<DD>,` to the `register_days!` invocation in the registry.
+fn register_day(year: u16, day: u8, day_padded: &str) -> Result<(), String> {
+    let mut registry = fs::read_to_string(REGISTRY_PATH).map_err(|e| e.to_string())?;
+
+    let year_module_path = format!("src/days/y{}/mod.rs", year);
+    let year_module = match fs::read_to_string(&year_module_path) {
+        Ok(year_module) => year_module,
+        Err(_) => {
+            registry = insert_after_last(&registry, "pub mod y", &format!("pub mod y{};\n", year));
+            YEAR_MODULE_TEMPLATE.replace("YEAR", &year.to_string())
+        }
+    };
+    let year_module = insert_after_last(
+        &year_module,
+        "pub mod day",
+        &format!("pub mod day{};\n", day_padded),
+    );
+    fs::write(&year_module_path, year_module).map_err(|e| e.to_string())?;
+
+    let entry = format!(
+        "    ({}, {}) => y{}::day{}::Day{},\n",
+        year, day, year, day_padded, day_padded
+    );
+    let start = registry
+        .find("register_days! {\n")
+        .ok_or("could not find `register_days!` invocation")?;
+    let end = start
+        + registry[start..]
+            .find("\n}")
+            .ok_or("could not find end of `register_days!` invocation")?
+        + 1;
+
+    let updated = format!("{}{}{}", &registry[..end], entry, &registry[end..]);
+    fs::write(REGISTRY_PATH, updated).map_err(|e| e.to_string())
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
+    let Some(year) = args.year.or_else(advent_of_code::default_year) else {
+        eprintln!("Need to specify a year with `--year` or `AOC_YEAR`. example: `cargo scaffold 7 --year 2022`");
+        process::exit(1);
+    };
+
+    // all paths below are relative to the crate root.
+    if let Err(e) = env::set_current_dir(advent_of_code::crate_root()) {
+        eprintln!("Failed to change to the crate root: {}", e);
+        process::exit(1);
+    }
+
+    let day = args.day;
     let day_padded = format!("{:02}", day);
+    let bin_name = advent_of_code::bin_name(year, day);
+
+    let input_path = format!("src/{}/inputs/{}.txt", year, day_padded);
+    let example_path = format!("src/{}/examples/{}.txt", year, day_padded);
+    let module_path = format!("src/bin/{}.rs", bin_name);
+    let solution_path = format!("src/days/y{}/day{}.rs", year, day_padded);
+
+    for dir in [
+        format!("src/days/y{}", year),
+        format!("src/{}/inputs", year),
+        format!("src/{}/examples", year),
+    ] {
+        if let Err(e) = fs::create_dir_all(&dir) {
+            eprintln!("Failed to create directory \"{}\": {}", dir, e);
+            process::exit(1);
+        }
+    }
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let mut file = match safe_create_file(&solution_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create solution file: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(
+        SOLUTION_TEMPLATE
+            .replace("DAY_PADDED", &day_padded)
+            .as_bytes(),
+    ) {
+        Ok(_) => {
+            println!("Created solution file \"{}\"", &solution_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to write solution contents: {}", e);
+            process::exit(1);
+        }
+    }
+
+    match register_day(year, day, &day_padded) {
+        Ok(_) => {
+            println!(
+                "Registered day {} of {} in \"{}\"",
+                day, year, REGISTRY_PATH
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to register solution: {}", e);
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,7 +237,12 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    let module_contents = MODULE_TEMPLATE
+        .replace("DAY_PADDED", &day_padded)
+        .replace("YEAR", &year.to_string())
+        .replace("DAY", &day.to_string());
+
+    match file.write_all(module_contents.as_bytes()) {
         Ok(_) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -108,7 +274,7 @@ fn main() {
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type {} to run your solution.",
+        style::bold(format!("`cargo solve {}`", &bin_name))
     );
 }
@@ -0,0 +1,546 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal, Read, Write};
+use std::panic;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::answers::Check;
+use crate::log;
+use crate::report::PartReport;
+use crate::runner::{self, DayRun, DayStatus, RunOptions, TestRun};
+use crate::style::{
+    bold, green, inverse, italic, red, ANSI_CLEAR, ANSI_ENTER_SCREEN, ANSI_LEAVE_SCREEN,
+};
+
+/// Days per row of the grid.
+const COLUMNS: u8 = 5;
+
+const LEGEND: &str = "✓ correct  ✗ wrong  ● solved  · not solved  ! failed  ? no input";
+const KEYS: &str = "←↑↓→/hjkl select  r run  b bench  t test  a run all  T test all  q quit";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    /// `q`, Esc or Ctrl+C.
+    Quit,
+    Char(char),
+}
+
+/// Splits the bytes read from the terminal into keys. Arrow keys arrive as escape sequences,
+/// other escape sequences are ignored.
+fn parse_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let key = match &bytes[i..] {
+            [0x1b, b'[' | b'O', rest @ ..] if !rest.is_empty() => {
+                // a sequence ends with its first byte in `@`..=`~`.
+                let len = rest
+                    .iter()
+                    .position(|b| (0x40..=0x7e).contains(b))
+                    .map_or(rest.len(), |end| end + 1);
+                i += 1 + len;
+                match &rest[..len] {
+                    b"A" => Some(Key::Up),
+                    b"B" => Some(Key::Down),
+                    b"C" => Some(Key::Right),
+                    b"D" => Some(Key::Left),
+                    _ => None,
+                }
+            }
+            [0x1b, ..] | [0x03, ..] | [b'q', ..] => Some(Key::Quit),
+            [b'\r' | b'\n', ..] => Some(Key::Enter),
+            [byte, ..] => Some(Key::Char(*byte as char)),
+            [] => None,
+        };
+        keys.extend(key);
+        i += 1;
+    }
+
+    keys
+}
+
+/// Changes or reads the settings of the terminal on stdin with `stty`.
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Reads single key presses without echoing them on the alternate screen.
+/// Restores the terminal when dropped, also if the dashboard panics.
+struct RawTerminal {
+    /// Settings before the dashboard started, from `stty -g`.
+    saved: String,
+}
+
+impl RawTerminal {
+    /// Also switches to the alternate screen, so that the dashboard does not scroll the output
+    /// before it away.
+    fn enter() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        // `-isig` delivers Ctrl+C as a key, so that the terminal is restored on quit.
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        print!("{}", ANSI_ENTER_SCREEN);
+        io::stdout().flush()?;
+        Ok(RawTerminal { saved })
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        print!("{}", ANSI_LEAVE_SCREEN);
+        let _ = io::stdout().flush();
+        if let Err(e) = stty(&[&self.saved]) {
+            eprintln!("Failed to restore the terminal: {}", e);
+        }
+    }
+}
+
+/// Collects panic messages and log lines while the dashboard covers the terminal, so that they do
+/// not end up in the grid. Writes the lines that were not shown to stderr when dropped.
+struct CapturedOutput;
+
+impl CapturedOutput {
+    fn start() -> Self {
+        log::capture(true);
+        panic::set_hook(Box::new(|info| {
+            let (file, line) = info.location().map_or(("<unknown>", 0), |location| {
+                (location.file(), location.line())
+            });
+            let message = runner::panic_message(info.payload());
+            log::emit("PANIC", log::parse_file_path(file), file, line, message);
+        }));
+        CapturedOutput
+    }
+}
+
+impl Drop for CapturedOutput {
+    fn drop(&mut self) {
+        // restores the default hook, which prints a panic of the dashboard itself from here on.
+        let _ = panic::take_hook();
+        for captured in log::capture(false) {
+            eprintln!("{}", captured.line);
+        }
+    }
+}
+
+/// A duration in at most six characters, e.g. `37.0µs` or `1.20ms`, to fit into a grid cell.
+fn short_duration(duration: Duration) -> String {
+    let (value, unit) = match duration.as_secs_f64() {
+        secs if secs < 1e-6 => (secs * 1e9, "ns"),
+        secs if secs < 1e-3 => (secs * 1e6, "µs"),
+        secs if secs < 1.0 => (secs * 1e3, "ms"),
+        secs => (secs, "s"),
+    };
+    let precision = match value {
+        value if value < 10.0 => 2,
+        value if value < 100.0 => 1,
+        _ => 0,
+    };
+    format!("{:.*}{}", precision, value, unit)
+}
+
+/// Symbol of a part in the grid, see [`LEGEND`]. Blank if the day did not run yet.
+fn part_symbol(run: Option<&DayRun>, part: u8) -> String {
+    let Some(run) = run else {
+        return " ".into();
+    };
+
+    match run.reports.iter().find(|report| report.part == part) {
+        Some(report) if report.is_failed() => red("!").to_string(),
+        Some(report) => match (report.check(), report.is_solved()) {
+            (Check::Regression, _) => red("✗").to_string(),
+            (Check::Pass, _) => green("✓").to_string(),
+            (_, true) => "●".into(),
+            (_, false) => "·".into(),
+        },
+        None => match run.status {
+            DayStatus::Ran | DayStatus::NotSolved => "·".into(),
+            DayStatus::MissingInput(_) | DayStatus::Unstructured(_) => "?".into(),
+            _ => red("!").to_string(),
+        },
+    }
+}
+
+/// Symbol of the unit tests of a day in the grid. Blank if they did not run yet.
+fn test_symbol(tests: Option<&TestRun>) -> String {
+    match tests {
+        None => " ".into(),
+        Some(TestRun::Finished { failed: 0, .. }) => green("✓").to_string(),
+        Some(TestRun::Finished { .. }) => red("✗").to_string(),
+        Some(TestRun::BuildFailed(_)) => red("!").to_string(),
+        Some(TestRun::NoResults) => "?".into(),
+    }
+}
+
+/// Explanation of a status without part results, as printed by `cargo all`.
+fn status_message(status: &DayStatus) -> Option<String> {
+    match status {
+        DayStatus::Ran => None,
+        DayStatus::NotSolved => Some("Not solved.".into()),
+        DayStatus::MissingInput(error) => Some(format!("Input missing: {}", error)),
+        DayStatus::Unstructured(output) => Some(output.trim().into()),
+        DayStatus::TimedOut(timeout) => Some(format!("Timed out after {:.2?}.", timeout)),
+        DayStatus::BuildFailed(error) => Some(format!("Build failed: {}", error)),
+        DayStatus::Panicked(message) => Some(format!("Panicked: {}", message)),
+        DayStatus::Crashed(status) => Some(format!("Crashed ({}).", status)),
+    }
+}
+
+fn part_line(report: &PartReport) -> String {
+    let answer = match (&report.answer, &report.error) {
+        (_, Some(error)) => red(format!("failed: {}", error)).to_string(),
+        (Some(answer), None) => answer.clone(),
+        (None, None) => "not solved".into(),
+    };
+    let marker = match (report.check(), &report.expected) {
+        (Check::Pass, _) => format!(" {}", green("✓")),
+        (Check::Regression, Some(expected)) => {
+            format!(" {}", red(format!("✗ expected {}", expected)))
+        }
+        _ => String::new(),
+    };
+    let timing = match report.bench {
+        Some(bench) => format!(
+            "(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, iterations: {})",
+            bench.median, bench.mean, bench.stddev, bench.min, bench.iterations
+        ),
+        None => format!("(elapsed: {:.2?})", report.duration),
+    };
+    format!(
+        "  Part {}: {}{} {}",
+        report.part,
+        answer,
+        marker,
+        italic(timing)
+    )
+}
+
+/// What the dashboard knows about a day.
+#[derive(Default)]
+struct DayState {
+    run: Option<DayRun>,
+    tests: Option<TestRun>,
+    /// Panic messages and log lines of the last run.
+    messages: Vec<String>,
+}
+
+struct Dashboard<F> {
+    year: u16,
+    options: RunOptions,
+    jobs: usize,
+    run_day: F,
+    /// States of days 1 to 25.
+    days: Vec<DayState>,
+    selected: u8,
+    /// Outcome of the last action, or what is running right now.
+    message: String,
+}
+
+impl<F: Fn(u8, RunOptions) -> DayRun + Sync> Dashboard<F> {
+    fn state(&self, day: u8) -> &DayState {
+        &self.days[day as usize - 1]
+    }
+
+    fn state_mut(&mut self, day: u8) -> &mut DayState {
+        &mut self.days[day as usize - 1]
+    }
+
+    fn cell(&self, day: u8) -> String {
+        let state = self.state(day);
+        let run = state.run.as_ref();
+        let time = run
+            .filter(|run| run.status == DayStatus::Ran)
+            .map_or_else(String::new, |run| short_duration(run.duration()));
+        let label = format!("{:02}", day);
+        let (marker, label) = if day == self.selected {
+            (">", inverse(label).to_string())
+        } else {
+            (" ", label)
+        };
+
+        format!(
+            "{}{} {}{} {:>6} {}",
+            marker,
+            label,
+            part_symbol(run, 1),
+            part_symbol(run, 2),
+            time,
+            test_symbol(state.tests.as_ref())
+        )
+    }
+
+    fn details(&self, out: &mut String) {
+        let day = self.selected;
+        let state = self.state(day);
+        let _ = writeln!(out, "{}", bold(format!("Day {:02}", day)));
+
+        match &state.run {
+            None => {
+                let _ = writeln!(out, "  Not run yet.");
+            }
+            Some(run) => {
+                if let Some(parse) = run.parse {
+                    let _ = writeln!(
+                        out,
+                        "  Parse: {}",
+                        italic(format!("(elapsed: {:.2?})", parse))
+                    );
+                }
+                for report in &run.reports {
+                    let _ = writeln!(out, "{}", part_line(report));
+                }
+                if let Some(message) = status_message(&run.status) {
+                    let _ = writeln!(out, "  {}", message);
+                }
+            }
+        }
+
+        if !state.messages.is_empty() {
+            let _ = writeln!(out, "  Messages:");
+            for message in &state.messages {
+                let _ = writeln!(out, "    {}", message);
+            }
+        }
+
+        match &state.tests {
+            None => {}
+            Some(TestRun::Finished {
+                passed, failed: 0, ..
+            }) => {
+                let _ = writeln!(out, "  Tests: {} {} passed", green("✓"), passed);
+            }
+            Some(TestRun::Finished {
+                passed,
+                failed,
+                failures,
+            }) => {
+                let _ = writeln!(
+                    out,
+                    "  Tests: {} {} failed, {} passed",
+                    red("✗"),
+                    failed,
+                    passed
+                );
+                for (name, message) in failures {
+                    let message = message.as_deref().unwrap_or("");
+                    let _ = writeln!(out, "    {}: {}", name, message.replace('\n', "\n      "));
+                }
+            }
+            Some(TestRun::BuildFailed(error)) => {
+                let _ = writeln!(out, "  Tests: {} {}", red("build failed:"), error);
+            }
+            Some(TestRun::NoResults) => {
+                let _ = writeln!(out, "  Tests: no results");
+            }
+        }
+    }
+
+    fn draw(&self) -> io::Result<()> {
+        let mut out = String::from(ANSI_CLEAR);
+        let _ = writeln!(
+            out,
+            "{}\n",
+            bold(format!("🎄 Advent of Code {} 🎄", self.year))
+        );
+
+        for row in 0..25 / COLUMNS {
+            let cells: Vec<String> = (1..=COLUMNS)
+                .map(|column| self.cell(row * COLUMNS + column))
+                .collect();
+            let _ = writeln!(out, "{}", cells.join(" "));
+        }
+
+        let total: Duration = self
+            .days
+            .iter()
+            .filter_map(|state| state.run.as_ref())
+            .map(DayRun::duration)
+            .sum();
+        let _ = writeln!(
+            out,
+            "\n{} {}\n",
+            bold("Total:"),
+            italic(format!("{:.2}ms", total.as_secs_f64() * 1000_f64))
+        );
+
+        self.details(&mut out);
+        let _ = writeln!(out, "\n{}", italic(&self.message));
+        let _ = writeln!(out, "\n{}\n{}", LEGEND, KEYS);
+
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(out.as_bytes())?;
+        stdout.flush()
+    }
+
+    /// Shows what is about to run, since the dashboard does not react to keys until it is done.
+    fn busy(&mut self, message: String) -> io::Result<()> {
+        self.message = message;
+        self.draw()
+    }
+
+    fn run_days(&mut self, days: &[u8], options: RunOptions) -> io::Result<()> {
+        self.busy(format!("Running {} day(s)…", days.len()))?;
+        let timer = Instant::now();
+        let runs = runner::run_parallel(days, self.jobs, |day| (self.run_day)(day, options));
+        for run in runs {
+            let day = run.day;
+            let state = self.state_mut(day);
+            state.run = Some(run);
+            state.messages.clear();
+        }
+        self.message = format!("Ran {} day(s) in {:.2?}.", days.len(), timer.elapsed());
+        self.collect_messages();
+        Ok(())
+    }
+
+    /// Moves captured lines into the details of their day. Lines of no day go below the grid.
+    fn collect_messages(&mut self) {
+        for captured in log::take_captured() {
+            match captured.day {
+                Some((year, day)) if year == self.year && (1..=25).contains(&day) => {
+                    self.state_mut(day).messages.push(captured.line);
+                }
+                _ => {
+                    self.message.push('\n');
+                    self.message.push_str(&captured.line);
+                }
+            }
+        }
+    }
+
+    fn run_tests(&mut self, day: u8) -> io::Result<()> {
+        self.busy(format!("Testing day {}…", day))?;
+        match runner::run_tests(self.year, day) {
+            Ok(tests) => {
+                self.state_mut(day).tests = Some(tests);
+                self.message = format!("Tested day {}.", day);
+            }
+            Err(e) => self.message = format!("Failed to run cargo test: {}", e),
+        }
+        Ok(())
+    }
+
+    fn select(&mut self, columns: i8, rows: i8) {
+        let index = self.selected as i8 - 1;
+        let column = (index % COLUMNS as i8 + columns).clamp(0, COLUMNS as i8 - 1);
+        let row = (index / COLUMNS as i8 + rows).clamp(0, 25 / COLUMNS as i8 - 1);
+        self.selected = (row * COLUMNS as i8 + column + 1) as u8;
+    }
+
+    /// Reacts to a key press. Returns `false` if the dashboard should close.
+    fn handle(&mut self, key: Key, days: &[u8]) -> io::Result<bool> {
+        let day = self.selected;
+        match key {
+            Key::Quit => return Ok(false),
+            Key::Left | Key::Char('h') => self.select(-1, 0),
+            Key::Down | Key::Char('j') => self.select(0, 1),
+            Key::Up | Key::Char('k') => self.select(0, -1),
+            Key::Right | Key::Char('l') => self.select(1, 0),
+            Key::Enter | Key::Char('r') => self.run_days(&[day], self.options)?,
+            Key::Char('b') => {
+                let options = RunOptions {
+                    bench: Some(self.options.bench.unwrap_or_default()),
+                    ..self.options
+                };
+                self.run_days(&[day], options)?;
+            }
+            Key::Char('a') => self.run_days(days, self.options)?,
+            Key::Char('t') => self.run_tests(day)?,
+            Key::Char('T') => {
+                for day in days.iter().copied() {
+                    if runner::is_implemented(self.year, day) {
+                        self.run_tests(day)?;
+                    }
+                }
+            }
+            Key::Char(_) => {}
+        }
+        Ok(true)
+    }
+}
+
+/// Shows the days in a grid that updates as days and their tests are run from the keyboard.
+/// Runs `days` with `run_day` on up to `jobs` threads first, as `cargo all` would.
+pub fn run(
+    year: u16,
+    days: &[u8],
+    jobs: usize,
+    options: RunOptions,
+    run_day: impl Fn(u8, RunOptions) -> DayRun + Sync,
+) -> io::Result<()> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err(io::Error::other(
+            "the dashboard needs an interactive terminal",
+        ));
+    }
+
+    // declared first, so that lines left over are written after the terminal is restored.
+    let _output = CapturedOutput::start();
+    let _terminal = RawTerminal::enter()?;
+    let mut dashboard = Dashboard {
+        year,
+        options,
+        jobs,
+        run_day,
+        days: (1..=25).map(|_| DayState::default()).collect(),
+        selected: days.first().copied().unwrap_or(1),
+        message: String::new(),
+    };
+    dashboard.run_days(days, options)?;
+    dashboard.draw()?;
+
+    let mut buffer = [0; 64];
+    loop {
+        let count = io::stdin().read(&mut buffer)?;
+        if count == 0 {
+            return Ok(());
+        }
+        for key in parse_keys(&buffer[..count]) {
+            if !dashboard.handle(key, days)? {
+                return Ok(());
+            }
+        }
+        dashboard.draw()?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(
+            parse_keys(b"\x1b[Ar\x1bOD\x1b[15~t"),
+            vec![Key::Up, Key::Char('r'), Key::Left, Key::Char('t')]
+        );
+        assert_eq!(parse_keys(b"\x1b"), vec![Key::Quit]);
+        assert_eq!(parse_keys(b"\x03\n"), vec![Key::Quit, Key::Enter]);
+    }
+
+    #[test]
+    fn test_short_duration() {
+        assert_eq!(short_duration(Duration::from_nanos(170)), "170ns");
+        assert_eq!(short_duration(Duration::from_nanos(37_040)), "37.0µs");
+        assert_eq!(short_duration(Duration::from_micros(1_204)), "1.20ms");
+        assert_eq!(short_duration(Duration::from_secs(12)), "12.0s");
+    }
+}
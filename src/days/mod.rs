@@ -0,0 +1,127 @@
+/*
+ * Solutions are registered here so that `cargo all` can call them in-process.
+ * Every year has its own module, e.g. `y2022`. `cargo scaffold` adds new years to the
+ * module list and new days to the `register_days!` list below.
+ * Days with a reference implementation for `cargo difftest` are added to `register_references!` by hand.
+ */
+use crate::bench::BenchOptions;
+#[cfg(any(test, feature = "difftest"))]
+use crate::differential::{self, Mismatch, Options};
+use crate::solution::{self, SolutionRun};
+
+pub mod y2022;
+
+/// A registered solution.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(day: u8, input: &str, part: Option<u8>, bench: Option<BenchOptions>) -> SolutionRun,
+}
+
+impl Day {
+    /// Parses the input and runs the selected part or both parts, see [`solution::run`].
+    pub fn run(&self, input: &str, part: Option<u8>, bench: Option<BenchOptions>) -> SolutionRun {
+        (self.solve)(self.day, input, part, bench)
+    }
+}
+
+macro_rules! register_days {
+    ($(($year:literal, $day:literal) => $($path:ident)::+,)*) => {
+        /// All registered solutions.
+        pub const DAYS: &[Day] = &[
+            $(Day {
+                year: $year,
+                day: $day,
+                solve: solution::run::<$($path)::+>,
+            },)*
+        ];
+    };
+}
+
+register_days! {
+    (2022, 1) => y2022::day01::Day01,
+    (2022, 2) => y2022::day02::Day02,
+    (2022, 3) => y2022::day03::Day03,
+    (2022, 4) => y2022::day04::Day04,
+    (2022, 5) => y2022::day05::Day05,
+    (2022, 6) => y2022::day06::Day06,
+    (2022, 7) => y2022::day07::Day07,
+    (2022, 8) => y2022::day08::Day08,
+    (2022, 9) => y2022::day09::Day09,
+}
+
+/// A registered [`Reference`](differential::Reference) implementation.
+#[cfg(any(test, feature = "difftest"))]
+pub struct ReferenceDay {
+    pub year: u16,
+    pub day: u8,
+    test: fn(options: Options) -> Result<u32, Box<Mismatch>>,
+}
+
+#[cfg(any(test, feature = "difftest"))]
+impl ReferenceDay {
+    /// Compares the solution against the reference on random inputs, see [`differential::run`].
+    pub fn test(&self, options: Options) -> Result<u32, Box<Mismatch>> {
+        (self.test)(options)
+    }
+}
+
+#[cfg(any(test, feature = "difftest"))]
+macro_rules! register_references {
+    ($(($year:literal, $day:literal) => $($path:ident)::+,)*) => {
+        /// Days with a reference implementation to test their solution against.
+        pub const REFERENCES: &[ReferenceDay] = &[
+            $(ReferenceDay {
+                year: $year,
+                day: $day,
+                test: differential::run::<$($path)::+>,
+            },)*
+        ];
+    };
+}
+
+#[cfg(any(test, feature = "difftest"))]
+register_references! {
+    (2022, 4) => y2022::day04::Day04,
+    (2022, 6) => y2022::day06::Day06,
+    (2022, 8) => y2022::day08::Day08,
+}
+
+/// Looks up the solution for a day.
+pub fn get(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+/// The most recent year with a registered solution.
+pub fn latest_year() -> Option<u16> {
+    DAYS.iter().map(|d| d.year).max()
+}
+
+/// Looks up the reference implementation for a day.
+#[cfg(any(test, feature = "difftest"))]
+pub fn get_reference(year: u16, day: u8) -> Option<&'static ReferenceDay> {
+    REFERENCES.iter().find(|d| d.year == year && d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_references_agree() {
+        let options = Options {
+            cases: 50,
+            seed: 2022,
+            part: None,
+        };
+        for reference in REFERENCES {
+            assert_eq!(
+                reference.test(options),
+                Ok(options.cases),
+                "day {} of {}",
+                reference.day,
+                reference.year
+            );
+        }
+    }
+}
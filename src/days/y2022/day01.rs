@@ -0,0 +1,99 @@
+use std::fmt;
+
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidCalories(String),
+    NoElves,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidCalories(line) => {
+                write!(f, "Expected a number of calories in \"{line}\"")
+            }
+            ParseError::NoElves => write!(f, "Did not find any elves"),
+        }
+    }
+}
+
+type CalorieList = Vec<u32>;
+
+fn find_total_calories_per_elf(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut elves_calories: Vec<CalorieList> = vec![];
+    let split_strs = input
+        .lines()
+        .map(|l| {
+            if l.is_empty() {
+                Ok(None)
+            } else {
+                l.parse::<u32>()
+                    .map(Some)
+                    .map_err(|_| ParseError::InvalidCalories(l.to_string()))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut current_elf_calories: CalorieList = vec![];
+    split_strs.into_iter().for_each(|f| match f {
+        Some(f) => {
+            let _ = &current_elf_calories.push(f);
+        }
+        None => {
+            elves_calories.push(current_elf_calories.clone());
+            current_elf_calories = Vec::new();
+        }
+    });
+
+    if elves_calories.is_empty() {
+        return Err(ParseError::NoElves);
+    }
+    Ok(elves_calories
+        .into_iter()
+        .map(|g| g.into_iter().sum())
+        .collect())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<u32>;
+    type Error = ParseError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        find_total_calories_per_elf(input)
+    }
+
+    fn part_one(calorie_sums: &Self::Parsed) -> Option<u32> {
+        let total_calories: u32 = *calorie_sums
+            .iter()
+            .max()
+            .expect("If there's no max value, some thing went wrong");
+
+        Some(total_calories)
+    }
+
+    fn part_two(calorie_sums: &Self::Parsed) -> Option<u32> {
+        let mut calorie_sums: Vec<u32> = calorie_sums.clone();
+        calorie_sums.sort();
+        calorie_sums.reverse();
+
+        let top_3: Vec<u32> = calorie_sums.into_iter().take(3).collect();
+
+        let top_3_sum = top_3.iter().sum::<u32>();
+
+        Some(top_3_sum)
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    Day01::parse(input).map(|calorie_sums| Day01::part_one(&calorie_sums))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    Day01::parse(input).map(|calorie_sums| Day01::part_two(&calorie_sums))
+}
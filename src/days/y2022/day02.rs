@@ -0,0 +1,177 @@
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    UnexpectedChar(char),
+    MalformedLine(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedChar(c) => write!(f, "Did not expect character {c}"),
+            ParseError::MalformedLine(line) => write!(f, "Expected two characters in \"{line}\""),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+fn char_to_choice(input: char) -> Result<Choice, ParseError> {
+    match input {
+        'A' | 'X' => Ok(Choice::Rock),
+        'B' | 'Y' => Ok(Choice::Paper),
+        'C' | 'Z' => Ok(Choice::Scissors),
+        c => Err(ParseError::UnexpectedChar(c)),
+    }
+}
+
+fn char_to_game_result(input: char) -> Result<GameResult, ParseError> {
+    match input {
+        'X' => Ok(GameResult::Lose),
+        'Y' => Ok(GameResult::Draw),
+        'Z' => Ok(GameResult::Win),
+        c => Err(ParseError::UnexpectedChar(c)),
+    }
+}
+
+fn lines_to_string_list(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .map(|c| c.to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn string_to_char_pair(string: &str) -> Result<(char, char), ParseError> {
+    string
+        .chars()
+        .filter(|c| c != &' ')
+        .collect_tuple()
+        .ok_or_else(|| ParseError::MalformedLine(string.to_string()))
+}
+
+#[derive(Debug)]
+pub enum GameResult {
+    Win,
+    Lose,
+    Draw,
+}
+
+fn choice_to_value(choice: &Choice) -> u32 {
+    match choice {
+        Choice::Rock => 1,
+        Choice::Paper => 2,
+        Choice::Scissors => 3,
+    }
+}
+
+fn game_result_to_value(game: &GameResult) -> u32 {
+    match game {
+        GameResult::Win => 6,
+        GameResult::Lose => 0,
+        GameResult::Draw => 3,
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    /// The opponent's choice and the second column, both as your choice and as the desired
+    /// game result, since the parts read the second column differently.
+    type Parsed = Vec<(Choice, Choice, GameResult)>;
+    type Error = ParseError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        lines_to_string_list(input)
+            .iter()
+            .map(|s| {
+                let (opponent_choice, second_column) = string_to_char_pair(s)?;
+                Ok((
+                    char_to_choice(opponent_choice)?,
+                    char_to_choice(second_column)?,
+                    char_to_game_result(second_column)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part_one(rounds: &Self::Parsed) -> Option<u32> {
+        let scores: Vec<u32> = rounds
+            .iter()
+            .map(|(opponents_choice, your_choice, _)| {
+                let game_result = match your_choice {
+                    Choice::Rock => match opponents_choice {
+                        Choice::Scissors => GameResult::Win,
+                        Choice::Paper => GameResult::Lose,
+                        Choice::Rock => GameResult::Draw,
+                    },
+                    Choice::Paper => match opponents_choice {
+                        Choice::Rock => GameResult::Win,
+                        Choice::Scissors => GameResult::Lose,
+                        Choice::Paper => GameResult::Draw,
+                    },
+                    Choice::Scissors => match opponents_choice {
+                        Choice::Paper => GameResult::Win,
+                        Choice::Rock => GameResult::Lose,
+                        Choice::Scissors => GameResult::Draw,
+                    },
+                };
+                let choice_score = choice_to_value(your_choice);
+                let game_score = game_result_to_value(&game_result);
+                choice_score + game_score
+            })
+            .collect();
+        let total_score = scores.iter().sum();
+        Some(total_score)
+    }
+
+    fn part_two(rounds: &Self::Parsed) -> Option<u32> {
+        let scores: Vec<u32> = rounds
+            .iter()
+            .map(|(opponents_choice, _, desired_game_result)| {
+                let your_choice = match desired_game_result {
+                    GameResult::Win => match opponents_choice {
+                        Choice::Rock => Choice::Paper,
+                        Choice::Paper => Choice::Scissors,
+                        Choice::Scissors => Choice::Rock,
+                    },
+                    GameResult::Draw => match opponents_choice {
+                        Choice::Rock => Choice::Rock,
+                        Choice::Paper => Choice::Paper,
+                        Choice::Scissors => Choice::Scissors,
+                    },
+                    GameResult::Lose => match opponents_choice {
+                        Choice::Rock => Choice::Scissors,
+                        Choice::Paper => Choice::Rock,
+                        Choice::Scissors => Choice::Paper,
+                    },
+                };
+                let choice_score = choice_to_value(&your_choice);
+                let game_score = game_result_to_value(desired_game_result);
+                choice_score + game_score
+            })
+            .collect();
+        let total_score = scores.iter().sum();
+        Some(total_score)
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    Day02::parse(input).map(|rounds| Day02::part_one(&rounds))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    Day02::parse(input).map(|rounds| Day02::part_two(&rounds))
+}
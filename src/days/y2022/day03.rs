@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    UnexpectedChar(char),
+    OddLength(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedChar(c) => write!(f, "Did not expect character {c}"),
+            ParseError::OddLength(line) => {
+                write!(
+                    f,
+                    "Expected two compartments of the same size in \"{line}\""
+                )
+            }
+        }
+    }
+}
+
+fn parse_rucksack(line: &str) -> Result<Vec<char>, ParseError> {
+    if let Some(c) = line.chars().find(|c| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::UnexpectedChar(c));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(ParseError::OddLength(line.to_string()));
+    }
+    Ok(line.chars().collect_vec())
+}
+
+fn find_unique_char(first: &[char], second: &[char]) -> char {
+    let mut first_set = HashSet::new();
+    first.iter().for_each(|c| {
+        first_set.insert(*c);
+    });
+    let mut second_set = HashSet::new();
+    second.iter().for_each(|c| {
+        second_set.insert(*c);
+    });
+    let common_chars = first_set.intersection(&second_set);
+    *common_chars
+        .exactly_one()
+        .expect("There were more or less than 1 chars")
+}
+
+fn char_to_priority(c: &char) -> u32 {
+    let mut num = if c.is_alphabetic() {
+        c.to_digit(36).unwrap()
+    } else {
+        panic!("Didn't expect {c}");
+    };
+    if c.is_lowercase() {
+        num -= 9;
+    } else if c.is_uppercase() {
+        num += 17;
+    } else {
+        panic!("Didn't expect {c}")
+    }
+    num
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<Vec<char>>;
+    type Error = ParseError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        input.lines().map(parse_rucksack).collect()
+    }
+
+    fn part_one(vec_of_lines: &Self::Parsed) -> Option<u32> {
+        let unique_chars: Vec<char> = vec_of_lines
+            .iter()
+            .map(|s| -> char {
+                let compartments = s.split_at(s.len() / 2);
+                let (first, second) = compartments;
+                find_unique_char(first, second)
+            })
+            .collect();
+        let priorities: Vec<u32> = unique_chars.iter().map(char_to_priority).collect();
+        Some(priorities.iter().sum())
+    }
+
+    fn part_two(vec_of_lines: &Self::Parsed) -> Option<u32> {
+        let elf_groups = vec_of_lines
+            .chunks(3)
+            .map(|elves| {
+                let elf_1: HashSet<char> = HashSet::from_iter(elves[0].clone());
+                let elf_2: HashSet<char> = HashSet::from_iter(elves[1].clone());
+                let elf_3: HashSet<char> = HashSet::from_iter(elves[2].clone());
+
+                let int_1: HashSet<char> =
+                    HashSet::from_iter(elf_1.intersection(&elf_2).copied().collect_vec());
+                let int_2: HashSet<char> =
+                    HashSet::from_iter(elf_2.intersection(&elf_3).copied().collect_vec());
+
+                let elves_1_and_2 = HashSet::from_iter(int_1);
+                let final_intersection = elves_1_and_2.intersection(&int_2).exactly_one().unwrap();
+                char_to_priority(final_intersection)
+            })
+            .collect_vec();
+        Some(elf_groups.iter().sum())
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    Day03::parse(input).map(|rucksacks| Day03::part_one(&rucksacks))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    Day03::parse(input).map(|rucksacks| Day03::part_two(&rucksacks))
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidRange(String),
+    MalformedLine(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidRange(range) => {
+                write!(f, "Expected a range like \"2-4\" instead of \"{range}\"")
+            }
+            ParseError::MalformedLine(line) => write!(f, "Expected two ranges in \"{line}\""),
+        }
+    }
+}
+
+fn one_range_partially_contains_another(ranges: &RangePair) -> bool {
+    let (range_1, range_2) = ranges;
+    range_2
+        .iter()
+        .map(|i| range_1.contains(i))
+        .reduce(|left, right| left || right)
+        .unwrap()
+        || range_1
+            .iter()
+            .map(|i| range_2.contains(i))
+            .reduce(|left, right| left || right)
+            .unwrap()
+}
+
+fn one_range_completely_contains_another(ranges: &RangePair) -> bool {
+    let (range_1, range_2) = ranges;
+    range_2
+        .iter()
+        .map(|i| range_1.contains(i))
+        .reduce(|left, right| left && right)
+        .unwrap()
+        || range_1
+            .iter()
+            .map(|i| range_2.contains(i))
+            .reduce(|left, right| left && right)
+            .unwrap()
+}
+
+type RangePair = (Vec<u32>, Vec<u32>);
+
+fn parse_range(range: &str) -> Result<Vec<u32>, ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .and_then(|(start, end)| Some((start.parse::<u32>().ok()?, end.parse::<u32>().ok()?)))
+        .filter(|(start, end)| start <= end)
+        .ok_or_else(|| ParseError::InvalidRange(range.to_string()))?;
+    Ok((start..=end).collect_vec())
+}
+
+fn pairs_of_ranges(input: &str) -> Result<Vec<RangePair>, ParseError> {
+    input
+        .lines()
+        .filter(|s| !s.is_empty())
+        .map(|line| {
+            let (first, second) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::MalformedLine(line.to_string()))?;
+            Ok((parse_range(first)?, parse_range(second)?))
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<RangePair>;
+    type Error = ParseError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        pairs_of_ranges(input)
+    }
+
+    fn part_one(pairs: &Self::Parsed) -> Option<u32> {
+        let contained_items = pairs
+            .iter()
+            .map(one_range_completely_contains_another)
+            .collect_vec();
+
+        // Count all values that are true
+        let n = contained_items
+            .into_iter()
+            .filter(|s| *s)
+            .collect_vec()
+            .len();
+        Some(n.try_into().unwrap())
+    }
+
+    fn part_two(pairs: &Self::Parsed) -> Option<u32> {
+        let contained_items = pairs
+            .iter()
+            .map(one_range_partially_contains_another)
+            .collect_vec();
+
+        // Count all values that are true
+        let n = contained_items
+            .into_iter()
+            .filter(|s| *s)
+            .collect_vec()
+            .len();
+        Some(n.try_into().unwrap())
+    }
+}
+
+/// A straightforward version of the solution that `cargo difftest` tests it against.
+#[cfg(any(test, feature = "difftest"))]
+mod reference {
+    use itertools::Itertools;
+
+    use super::Day04;
+    use crate::differential::{Reference, Rng};
+
+    fn random_range(rng: &mut Rng) -> String {
+        let start = rng.range(1..=20);
+        let end = rng.range(start..=20);
+        format!("{}-{}", start, end)
+    }
+
+    /// Bounds of the two ranges of every pair, compared directly instead of through their sections.
+    fn bounds(input: &str) -> Vec<[u32; 4]> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let numbers = line
+                    .split([',', '-'])
+                    .map(|n| n.parse().unwrap())
+                    .collect_vec();
+                [numbers[0], numbers[1], numbers[2], numbers[3]]
+            })
+            .collect()
+    }
+
+    impl Reference for Day04 {
+        fn generate(rng: &mut Rng) -> String {
+            (0..rng.range(1..=20))
+                .map(|_| format!("{},{}", random_range(rng), random_range(rng)))
+                .join("\n")
+        }
+
+        fn reference_part_one(input: &str) -> Option<u32> {
+            let contained = bounds(input)
+                .into_iter()
+                .filter(|&[a, b, c, d]| (a <= c && d <= b) || (c <= a && b <= d))
+                .count();
+            Some(contained as u32)
+        }
+
+        fn reference_part_two(input: &str) -> Option<u32> {
+            let overlapping = bounds(input)
+                .into_iter()
+                .filter(|&[a, b, c, d]| a <= d && c <= b)
+                .count();
+            Some(overlapping as u32)
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    Day04::parse(input).map(|pairs| Day04::part_one(&pairs))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    Day04::parse(input).map(|pairs| Day04::part_two(&pairs))
+}
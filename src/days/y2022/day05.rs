@@ -0,0 +1,227 @@
+use std::{cell::RefCell, collections::HashMap, fmt};
+
+use itertools::Itertools;
+
+use crate::solution::Solution;
+use crate::visualize;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingStacks,
+    MalformedStep(String),
+    UnknownStack(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::MissingStacks => write!(f, "Did not find a drawing of the stacks"),
+            ParseError::MalformedStep(line) => {
+                write!(
+                    f,
+                    "Expected \"move <n> from <stack> to <stack>\" in \"{line}\""
+                )
+            }
+            ParseError::UnknownStack(stack) => write!(f, "Did not find stack {stack}"),
+        }
+    }
+}
+
+type Stack = RefCell<Vec<char>>;
+type Stacks = HashMap<usize, Stack>;
+
+fn parse_initial_stacks(input: &Vec<&str>) -> Result<Stacks, ParseError> {
+    let stack_data_str = input.split_last().ok_or(ParseError::MissingStacks)?.1;
+
+    let mut columns: Stacks = HashMap::new();
+
+    let mut row = 0;
+    stack_data_str.iter().rev().for_each(|l| {
+        row += 1;
+        l.chars()
+            .chunks(4)
+            .into_iter()
+            .enumerate()
+            .for_each(|(column_index, chunk)| {
+                let column = column_index + 1;
+                let probably_one_char = chunk
+                    .into_iter()
+                    .filter(|c| c.is_alphabetic())
+                    .collect_vec();
+                if let Ok(crate_letter) = probably_one_char.into_iter().exactly_one() {
+                    match columns.get_mut(&column) {
+                        None => {
+                            let _ = columns.insert(column, RefCell::new(vec![crate_letter]));
+                        }
+                        Some(col) => (*col).borrow_mut().push(crate_letter),
+                    };
+                };
+            });
+    });
+    Ok(columns)
+}
+
+#[derive(Debug)]
+pub struct ProcedureStep {
+    crates_to_move: usize,
+    from_stack: usize,
+    to_stack: usize,
+}
+
+type MoveProcedure = Vec<ProcedureStep>;
+
+fn parse_move_procedure(input: &Vec<&str>) -> Result<MoveProcedure, ParseError> {
+    input
+        .iter()
+        .map(|i| {
+            let (crates_to_move, from_stack, to_stack) = i
+                .split_whitespace()
+                .filter(|string| {
+                    !(string.contains("move") || string.contains("from") || string.contains("to"))
+                })
+                .map(|string| string.parse::<usize>().ok())
+                .collect_tuple()
+                .and_then(|(n, from, to)| Some((n?, from?, to?)))
+                .ok_or_else(|| ParseError::MalformedStep(i.to_string()))?;
+            Ok(ProcedureStep {
+                crates_to_move,
+                from_stack,
+                to_stack,
+            })
+        })
+        .collect()
+}
+
+/// Draws the stacks like the puzzle input. There is a row for every crate, so that all frames have the same size.
+fn render_stacks(stacks: &Stacks) -> String {
+    let columns = stacks.keys().max().copied().unwrap_or(0);
+    let height: usize = stacks.values().map(|stack| stack.borrow().len()).sum();
+
+    let mut frame = String::new();
+    for row in (0..height).rev() {
+        let line = (1..=columns)
+            .map(|column| {
+                match stacks
+                    .get(&column)
+                    .and_then(|stack| stack.borrow().get(row).copied())
+                {
+                    Some(crate_letter) => format!("[{}]", crate_letter),
+                    None => "   ".into(),
+                }
+            })
+            .join(" ");
+        frame.push_str(line.trim_end());
+        frame.push('\n');
+    }
+    frame.push_str(
+        &(1..=columns)
+            .map(|column| format!(" {} ", column))
+            .join(" "),
+    );
+    frame.push('\n');
+    frame
+}
+
+fn parse_input(input: &str) -> Result<(Stacks, MoveProcedure), ParseError> {
+    let lines = input.lines();
+    let initial_stacks_str_repr = lines
+        .clone()
+        .take_while(|line| !line.is_empty())
+        .collect_vec();
+    let move_proc_str_repr = lines
+        .skip_while(|line| !line.is_empty())
+        .skip(1)
+        .collect_vec();
+    let stacks = parse_initial_stacks(&initial_stacks_str_repr)?;
+    let move_procedure = parse_move_procedure(&move_proc_str_repr)?;
+
+    let unknown_stack = move_procedure
+        .iter()
+        .flat_map(|step| [step.from_stack, step.to_stack])
+        .find(|stack| !stacks.contains_key(stack));
+    match unknown_stack {
+        Some(stack) => Err(ParseError::UnknownStack(stack)),
+        None => Ok((stacks, move_procedure)),
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = (Stacks, MoveProcedure);
+    type Error = ParseError;
+    type PartOne = Option<String>;
+    type PartTwo = Option<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one((stacks, move_procedure): &Self::Parsed) -> Option<String> {
+        // moving crates mutates the stacks, work on a copy to keep the parsed input intact.
+        let stacks = stacks.clone();
+        visualize::frame(|| render_stacks(&stacks));
+        let procs = move_procedure.iter();
+        for proc in procs {
+            for _crate_num in 1..=proc.crates_to_move {
+                let stack_to_move_from = stacks
+                    .get(&proc.from_stack)
+                    .expect("Did not find a stack to move from");
+                let stack_to_move_to = stacks
+                    .get(&proc.to_stack)
+                    .expect("Did not find a stack to move to");
+                if let Some(crate_to_move) = stack_to_move_from.borrow_mut().pop() {
+                    stack_to_move_to.borrow_mut().push(crate_to_move);
+                }
+                visualize::frame(|| render_stacks(&stacks));
+            }
+        }
+        let mut answer = String::new();
+        let sorted_stacks = stacks.into_iter().sorted_by(|a, b| a.0.cmp(&b.0));
+        for (_, stack) in sorted_stacks {
+            let val = stack.borrow_mut().pop().unwrap();
+            answer.push(val);
+        }
+        Some(answer)
+    }
+
+    fn part_two((stacks, move_procedure): &Self::Parsed) -> Option<String> {
+        let stacks = stacks.clone();
+        visualize::frame(|| render_stacks(&stacks));
+        let procs = move_procedure.iter();
+        for proc in procs {
+            let mut temp_vec = Vec::new();
+            let stack_to_move_from = stacks
+                .get(&proc.from_stack)
+                .expect("Did not find a stack to move from");
+            let stack_to_move_to = stacks
+                .get(&proc.to_stack)
+                .expect("Did not find a stack to move to");
+            for _crate_num in 1..=proc.crates_to_move {
+                if let Some(crate_to_move) = stack_to_move_from.borrow_mut().pop() {
+                    temp_vec.push(crate_to_move);
+                }
+            }
+            for _ in 1..=temp_vec.len() {
+                let crate_to_move = temp_vec.pop().unwrap();
+                stack_to_move_to.borrow_mut().push(crate_to_move);
+            }
+            visualize::frame(|| render_stacks(&stacks));
+        }
+        let mut answer = String::new();
+        let sorted_stacks = stacks.into_iter().sorted_by(|a, b| a.0.cmp(&b.0));
+        for (_, stack) in sorted_stacks {
+            let val = stack.borrow_mut().pop().unwrap();
+            answer.push(val);
+        }
+        Some(answer)
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<String>, ParseError> {
+    Day05::parse(input).map(|parsed| Day05::part_one(&parsed))
+}
+
+pub fn part_two(input: &str) -> Result<Option<String>, ParseError> {
+    Day05::parse(input).map(|parsed| Day05::part_two(&parsed))
+}
@@ -0,0 +1,122 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    UnexpectedChar(char),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedChar(c) => write!(f, "Did not expect character {c:?}"),
+        }
+    }
+}
+
+fn solve_for_packet_length(input: &[char], length: usize) -> Option<u32> {
+    let mut chars_in: VecDeque<char> = input.iter().copied().collect();
+    let mut packet = VecDeque::new();
+
+    fn duplicates_present(p: VecDeque<char>) -> bool {
+        !p.iter().duplicates().collect_vec().is_empty()
+    }
+
+    let mut num_of_chars = 0;
+    while duplicates_present(packet.clone()) || packet.len() < length {
+        if packet.len() < length {
+            packet.push_back(chars_in.pop_front()?);
+        } else {
+            packet.pop_front();
+            packet.push_back(chars_in.pop_front()?);
+        }
+        num_of_chars += 1;
+    }
+
+    Some(num_of_chars as u32)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Vec<char>;
+    type Error = ParseError;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let chars = input.trim_end();
+        match chars.chars().find(|c| !c.is_ascii_lowercase()) {
+            Some(c) => Err(ParseError::UnexpectedChar(c)),
+            None => Ok(chars.chars().collect()),
+        }
+    }
+
+    fn part_one(chars: &Self::Parsed) -> Option<u32> {
+        solve_for_packet_length(chars, 4)
+    }
+
+    fn part_two(chars: &Self::Parsed) -> Option<u32> {
+        solve_for_packet_length(chars, 14)
+    }
+}
+
+/// A straightforward version of the solution that `cargo difftest` tests it against.
+#[cfg(any(test, feature = "difftest"))]
+mod reference {
+    use itertools::Itertools;
+
+    use super::Day06;
+    use crate::differential::{self, Reference, Rng};
+
+    /// Position after the first window of `length` distinct characters, checking every window on its own.
+    fn first_marker(input: &str, length: usize) -> Option<u32> {
+        let chars = input.trim_end().chars().collect_vec();
+        chars
+            .windows(length)
+            .position(|window| window.iter().all_unique())
+            .map(|start| (start + length) as u32)
+    }
+
+    impl Reference for Day06 {
+        /// Noise from a few letters, which can not contain a start-of-message marker, followed by one.
+        fn generate(rng: &mut Rng) -> String {
+            let noise = ('a'..='h').collect_vec();
+            let mut marker = ('a'..='z').collect_vec();
+            rng.shuffle(&mut marker);
+
+            (0..rng.range(0..=60))
+                .map(|_| *rng.pick(&noise))
+                .chain(marker.into_iter().take(14))
+                .collect()
+        }
+
+        fn reference_part_one(input: &str) -> Option<u32> {
+            first_marker(input, 4)
+        }
+
+        fn reference_part_two(input: &str) -> Option<u32> {
+            first_marker(input, 14)
+        }
+
+        fn is_valid(input: &str) -> bool {
+            first_marker(input, 14).is_some()
+        }
+
+        fn shrink(input: &str) -> Vec<String> {
+            differential::remove_chars(input)
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    Day06::parse(input).map(|chars| Day06::part_one(&chars))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    Day06::parse(input).map(|chars| Day06::part_two(&chars))
+}
@@ -0,0 +1,126 @@
+use std::{collections::HashMap, fmt, path::PathBuf};
+
+use itertools::Itertools;
+
+use crate::solution::Solution;
+use crate::{debug, trace};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MalformedFile(String),
+    NoFiles,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::MalformedFile(line) => {
+                write!(f, "Expected a size and a file name in \"{line}\"")
+            }
+            ParseError::NoFiles => write!(f, "Did not find any files"),
+        }
+    }
+}
+
+type Files = HashMap<PathBuf, usize>;
+
+fn input_to_files(input: &str) -> Result<Files, ParseError> {
+    let mut pwd = PathBuf::new();
+    let mut files = HashMap::new();
+
+    for l in input.lines() {
+        if l.starts_with('$') {
+            let command_contents = l.chars().skip(2).collect::<String>();
+            if command_contents.starts_with("cd") {
+                let destination = command_contents.chars().skip(3).collect::<String>();
+                if destination == ".." {
+                    pwd.pop();
+                } else {
+                    pwd.push(&destination);
+                }
+            }
+        } else {
+            if !l.starts_with('d') {
+                let (size, filename_str) = l
+                    .split_whitespace()
+                    .collect_tuple::<(&str, &str)>()
+                    .and_then(|(size_str, filename_str)| {
+                        Some((size_str.parse().ok()?, filename_str))
+                    })
+                    .ok_or_else(|| ParseError::MalformedFile(l.to_string()))?;
+
+                let filename = PathBuf::from(filename_str);
+
+                let mut full_path = pwd.clone();
+                full_path.push(&filename);
+
+                trace!("file {} of size {}", full_path.display(), size);
+                files.insert(full_path, size);
+            }
+        }
+    }
+    if files.is_empty() {
+        return Err(ParseError::NoFiles);
+    }
+    Ok(files)
+}
+
+fn input_to_dirs(input: &str) -> Result<Files, ParseError> {
+    let files = input_to_files(input)?;
+    let mut dirs: Files = HashMap::new();
+
+    files.iter().for_each(|(path, size)| {
+        path.ancestors().for_each(|a| {
+            if let Some(parent) = a.parent() {
+                dirs.entry(parent.to_path_buf())
+                    .and_modify(|esize| *esize += size)
+                    .or_insert(*size);
+            }
+        });
+    });
+
+    debug!("{} files in {} directories", files.len(), dirs.len());
+    Ok(dirs)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Files;
+    type Error = ParseError;
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        input_to_dirs(input)
+    }
+
+    fn part_one(dirs: &Self::Parsed) -> Option<usize> {
+        let under_100k = dirs
+            .values()
+            .copied()
+            .filter(|s| *s <= 100000)
+            .collect_vec();
+
+        Some(under_100k.iter().sum())
+    }
+
+    fn part_two(dirs_hashmap: &Self::Parsed) -> Option<usize> {
+        let dirs = dirs_hashmap.values();
+
+        // parsing found at least one file, so there is a root directory that frees all space.
+        let total_disk_usage = dirs.clone().max()?;
+
+        dirs.filter(|d| total_disk_usage - *d <= 40000000)
+            .min()
+            .copied()
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>, ParseError> {
+    Day07::parse(input).map(|dirs| Day07::part_one(&dirs))
+}
+
+pub fn part_two(input: &str) -> Result<Option<usize>, ParseError> {
+    Day07::parse(input).map(|dirs| Day07::part_two(&dirs))
+}
@@ -0,0 +1,310 @@
+use std::fmt;
+
+use cgmath::Point2;
+use itertools::Itertools;
+
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    EmptyGrid,
+    UnexpectedChar(char),
+    RaggedRow(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::EmptyGrid => write!(f, "Did not find any trees"),
+            ParseError::UnexpectedChar(c) => write!(f, "Did not expect character {c}"),
+            ParseError::RaggedRow(line) => {
+                write!(f, "Expected \"{line}\" to be as wide as the first row")
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TreeGrid {
+    trees: Vec<Tree>,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Tree {
+    position: Point2<usize>,
+    height: u32,
+}
+
+fn parse_input(input: &str) -> Result<TreeGrid, ParseError> {
+    let mut trees = vec![];
+
+    let height = input.lines().collect_vec().len();
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+    if width == 0 {
+        return Err(ParseError::EmptyGrid);
+    }
+
+    for (rnum, line) in input.lines().enumerate() {
+        if line.chars().count() != width {
+            return Err(ParseError::RaggedRow(line.to_string()));
+        }
+        for (cnum, height) in line.chars().enumerate() {
+            let height = height
+                .to_digit(10)
+                .ok_or(ParseError::UnexpectedChar(height))?;
+            let position = Point2::new(cnum + 1, rnum + 1);
+
+            trees.push(Tree { position, height })
+        }
+    }
+    Ok(TreeGrid {
+        trees,
+        width,
+        height,
+    })
+}
+
+fn tree_is_on_edge(pos: &Point2<usize>, max_x: usize, max_y: usize) -> bool {
+    pos.x == 1 || pos.y == 1 || pos.x == max_x || pos.y == max_y
+}
+
+fn find_visible_trees(grid: &TreeGrid) -> Vec<&Tree> {
+    grid.trees
+        .iter()
+        .filter(|tree| {
+            let all_other_trees = grid.trees.iter().filter(|other_tree| {
+                let is_same_tree = other_tree.position == tree.position;
+                !is_same_tree || !tree_is_on_edge(&other_tree.position, grid.width, grid.height)
+            });
+
+            let trees_in_same_row = all_other_trees
+                .clone()
+                .filter(|other_tree| other_tree.position.y == tree.position.y);
+
+            let trees_in_same_col =
+                all_other_trees.filter(|other_tree| other_tree.position.x == tree.position.x);
+
+            let taller_than_left = || -> bool {
+                trees_in_same_row
+                    .clone()
+                    .filter(|t| t.position.x < tree.position.x)
+                    .all(|t| tree.height > t.height)
+            };
+            let taller_than_right = || -> bool {
+                trees_in_same_row
+                    .clone()
+                    .filter(|t| t.position.x > tree.position.x)
+                    .all(|t| tree.height > t.height)
+            };
+            let taller_than_above = || -> bool {
+                trees_in_same_col
+                    .clone()
+                    .filter(|t| t.position.y > tree.position.y)
+                    .all(|t| tree.height > t.height)
+            };
+            let taller_than_below = || -> bool {
+                trees_in_same_col
+                    .clone()
+                    .filter(|t| t.position.y < tree.position.y)
+                    .all(|t| tree.height > t.height)
+            };
+
+            tree_is_on_edge(&tree.position, grid.width, grid.height)
+                || taller_than_left()
+                || taller_than_right()
+                || taller_than_above()
+                || taller_than_below()
+        })
+        .collect_vec()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = TreeGrid;
+    type Error = ParseError;
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(grid: &Self::Parsed) -> Option<usize> {
+        let num_of_visible_trees = find_visible_trees(grid).len();
+        Some(num_of_visible_trees)
+    }
+
+    fn part_two(grid: &Self::Parsed) -> Option<usize> {
+        let scenic_score = grid
+            .trees
+            .iter()
+            .map(|tree| {
+                let all_other_trees = grid.trees.iter().filter(|other_tree| {
+                    let is_same_tree = other_tree.position == tree.position;
+                    let is_same_col = other_tree.position.x == tree.position.x;
+                    let is_same_row = other_tree.position.y == tree.position.y;
+
+                    !is_same_tree && (is_same_col || is_same_row)
+                });
+
+                let visible_trees = |trees: Vec<&Tree>| {
+                    let mut visible_trees = 0;
+                    for t in trees {
+                        if t.height < tree.height {
+                            visible_trees += 1;
+                        } else if t.height >= tree.height {
+                            visible_trees += 1;
+                            break;
+                        }
+                    }
+                    visible_trees
+                };
+
+                let trees_above = all_other_trees
+                    .clone()
+                    .filter(|other_tree| {
+                        other_tree.position.x == tree.position.x
+                            && other_tree.position.y < tree.position.y
+                    })
+                    .sorted_by(|a, b| b.position.y.cmp(&a.position.y))
+                    .collect_vec();
+                let visible_trees_above = visible_trees(trees_above);
+
+                let trees_left = all_other_trees
+                    .clone()
+                    .filter(|other_tree| {
+                        other_tree.position.y == tree.position.y
+                            && other_tree.position.x < tree.position.x
+                    })
+                    .sorted_by(|a, b| b.position.x.cmp(&a.position.x))
+                    .collect_vec();
+                let visible_trees_left = visible_trees(trees_left);
+
+                let trees_below = all_other_trees
+                    .clone()
+                    .filter(|other_tree| {
+                        other_tree.position.x == tree.position.x
+                            && other_tree.position.y > tree.position.y
+                    })
+                    .sorted_by(|a, b| a.position.y.cmp(&b.position.y))
+                    .collect_vec();
+                let visible_trees_below = visible_trees(trees_below);
+
+                let trees_right = all_other_trees
+                    .filter(|other_tree| {
+                        other_tree.position.y == tree.position.y
+                            && other_tree.position.x > tree.position.x
+                    })
+                    .sorted_by(|a, b| a.position.x.cmp(&b.position.x))
+                    .collect_vec();
+                let visible_trees_right = visible_trees(trees_right);
+
+                visible_trees_above * visible_trees_below * visible_trees_left * visible_trees_right
+            })
+            .max()
+            .unwrap();
+
+        Some(scenic_score)
+    }
+}
+
+/// A straightforward version of the solution that `cargo difftest` tests it against.
+#[cfg(any(test, feature = "difftest"))]
+mod reference {
+    use itertools::Itertools;
+
+    use super::Day08;
+    use crate::differential::{self, Reference, Rng};
+
+    fn digit_rows(input: &str) -> Vec<Vec<u32>> {
+        input
+            .lines()
+            .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+            .collect()
+    }
+
+    /// Heights of the trees from `(x, y)` to the edge in each direction, nearest first.
+    fn lines_of_sight(rows: &[Vec<u32>], x: usize, y: usize) -> [Vec<u32>; 4] {
+        let row = &rows[y];
+        let column = rows.iter().map(|row| row[x]).collect_vec();
+        [
+            row[..x].iter().rev().copied().collect(),
+            row[x + 1..].to_vec(),
+            column[..y].iter().rev().copied().collect(),
+            column[y + 1..].to_vec(),
+        ]
+    }
+
+    fn positions(rows: &[Vec<u32>]) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..rows.len()).flat_map(move |y| (0..rows[y].len()).map(move |x| (x, y)))
+    }
+
+    impl Reference for Day08 {
+        fn generate(rng: &mut Rng) -> String {
+            let (width, height) = (rng.range(1..=8), rng.range(1..=8));
+            (0..height)
+                .map(|_| (0..width).map(|_| rng.range(0..=9).to_string()).join(""))
+                .join("\n")
+        }
+
+        fn reference_part_one(input: &str) -> Option<usize> {
+            let rows = digit_rows(input);
+            let visible = positions(&rows)
+                .filter(|&(x, y)| {
+                    lines_of_sight(&rows, x, y)
+                        .iter()
+                        .any(|trees| trees.iter().all(|&tree| tree < rows[y][x]))
+                })
+                .count();
+            Some(visible)
+        }
+
+        fn reference_part_two(input: &str) -> Option<usize> {
+            let rows = digit_rows(input);
+            positions(&rows)
+                .map(|(x, y)| {
+                    lines_of_sight(&rows, x, y)
+                        .iter()
+                        .map(
+                            |trees| match trees.iter().position(|&tree| tree >= rows[y][x]) {
+                                Some(blocking) => blocking + 1,
+                                None => trees.len(),
+                            },
+                        )
+                        .product()
+                })
+                .max()
+        }
+
+        fn is_valid(input: &str) -> bool {
+            let widths = input.lines().map(str::len).collect_vec();
+            !widths.is_empty() && widths.iter().all(|&width| width > 0 && width == widths[0])
+        }
+
+        /// Removes rows, and columns by removing them from every row.
+        fn shrink(input: &str) -> Vec<String> {
+            let width = input.lines().next().map_or(0, str::len);
+            let columns = (0..width).map(|x| {
+                input
+                    .lines()
+                    .map(|line| format!("{}{}", &line[..x], &line[x + 1..]))
+                    .join("\n")
+            });
+            differential::remove_lines(input)
+                .into_iter()
+                .chain(columns)
+                .collect()
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>, ParseError> {
+    Day08::parse(input).map(|grid| Day08::part_one(&grid))
+}
+
+pub fn part_two(input: &str) -> Result<Option<usize>, ParseError> {
+    Day08::parse(input).map(|grid| Day08::part_two(&grid))
+}
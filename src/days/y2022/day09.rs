@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use cgmath::Point2;
+use itertools::Itertools;
+
+use crate::solution::Solution;
+use crate::visualize;
+
+/// Cells drawn left and right, and above and below the head in frames.
+const VIEW_WIDTH: i32 = 20;
+const VIEW_HEIGHT: i32 = 10;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    UnexpectedDirection(String),
+    MalformedLine(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedDirection(c) => write!(f, "Unexpected input {c}"),
+            ParseError::MalformedLine(line) => {
+                write!(
+                    f,
+                    "Expected a direction and a number of steps in \"{line}\""
+                )
+            }
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (dir_ch, steps) = line
+                .split_whitespace()
+                .collect_tuple()
+                .and_then(|(dir_ch, step_ch)| Some((dir_ch, step_ch.parse::<u32>().ok()?)))
+                .ok_or_else(|| ParseError::MalformedLine(line.to_string()))?;
+            let direction = match dir_ch {
+                "U" => Direction::Up,
+                "D" => Direction::Down,
+                "L" => Direction::Left,
+                "R" => Direction::Right,
+                c => return Err(ParseError::UnexpectedDirection(c.to_string())),
+            };
+            Ok(Instruction {
+                direction,
+                number_of_moves: steps,
+            })
+        })
+        .collect()
+}
+
+pub struct Instruction {
+    direction: Direction,
+    number_of_moves: u32,
+}
+
+struct Rope {
+    segments: Vec<Point>,
+    unique_tail_locations: HashSet<Point>,
+}
+
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Rope {
+    fn new(n: usize) -> Self {
+        Self {
+            segments: vec![Point { x: 0, y: 0 }; n],
+            unique_tail_locations: HashSet::from([Point { x: 0, y: 0 }]),
+        }
+    }
+
+    fn move_rope(&mut self, instruction: &Instruction) {
+        for _move in 0..instruction.number_of_moves {
+            self.move_head(&instruction.direction);
+            self.update_rest_of_segments();
+            visualize::frame(|| self.render());
+        }
+    }
+
+    /// Draws the area around the head like the puzzle description, with visited tail positions as `#`.
+    fn render(&self) -> String {
+        let head = self.segments[0];
+        let start = Point { x: 0, y: 0 };
+
+        let mut frame = String::new();
+        for y in (head.y - VIEW_HEIGHT..=head.y + VIEW_HEIGHT).rev() {
+            for x in head.x - VIEW_WIDTH..=head.x + VIEW_WIDTH {
+                let point = Point { x, y };
+                let cell = match self.segments.iter().position(|segment| *segment == point) {
+                    Some(0) => 'H',
+                    Some(_) if self.segments.len() == 2 => 'T',
+                    Some(i) => char::from_digit(i as u32, 10).unwrap_or('T'),
+                    None if self.unique_tail_locations.contains(&point) => '#',
+                    None if point == start => 's',
+                    None => '.',
+                };
+                frame.push(cell);
+            }
+            frame.push('\n');
+        }
+        frame
+    }
+
+    fn move_head(&mut self, direction: &Direction) {
+        let head = self.segments.first_mut().unwrap();
+        match direction {
+            Direction::Up => head.y += 1,
+            Direction::Right => head.x += 1,
+            Direction::Left => head.x -= 1,
+            Direction::Down => head.y -= 1,
+        }
+    }
+
+    fn update_rest_of_segments(&mut self) {
+        let mut tail = self.segments[0];
+
+        for segment in self.segments.iter_mut().skip(1) {
+            let (dx, dy) = (tail.x - segment.x, tail.y - segment.y);
+
+            if dx.abs() > 1 || dy.abs() > 1 {
+                segment.x += dx.signum();
+                segment.y += dy.signum();
+            }
+
+            tail = *segment;
+        }
+        self.unique_tail_locations
+            .insert(*self.segments.last().unwrap());
+    }
+}
+
+type Point = Point2<i32>;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<Instruction>;
+    type Error = ParseError;
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(move_instruction: &Self::Parsed) -> Option<usize> {
+        let mut rope = Rope::new(2);
+        move_instruction
+            .iter()
+            .for_each(|instruction| rope.move_rope(instruction));
+        Some(rope.unique_tail_locations.len())
+    }
+
+    fn part_two(move_instruction: &Self::Parsed) -> Option<usize> {
+        let mut rope = Rope::new(10);
+        move_instruction
+            .iter()
+            .for_each(|instruction| rope.move_rope(instruction));
+        Some(rope.unique_tail_locations.len())
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>, ParseError> {
+    Day09::parse(input).map(|instructions| Day09::part_one(&instructions))
+}
+
+pub fn part_two(input: &str) -> Result<Option<usize>, ParseError> {
+    Day09::parse(input).map(|instructions| Day09::part_two(&instructions))
+}
@@ -0,0 +1,12 @@
+/*
+ * Solutions for 2022. `cargo scaffold` adds new days to this list.
+ */
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
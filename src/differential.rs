@@ -0,0 +1,309 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::report::Answer;
+use crate::runner;
+use crate::solution::Solution;
+
+/// Random inputs per day of `cargo difftest` without `--cases`.
+pub const DEFAULT_CASES: u32 = 1000;
+
+/// SplitMix64, a small random number generator that is good enough for puzzle inputs.
+/// The same seed always generates the same inputs, so that mismatches can be reproduced.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, e.g. `rng.range(1..=99)`.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        start + (self.next_u64() % (end - start + 1) as u64) as usize
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+/// A straightforward implementation of a day that its optimized [`Solution`] is tested against
+/// with random inputs by `cargo difftest`. Register it with `register_references!` in `src/days/mod.rs`.
+pub trait Reference: Solution {
+    /// A random puzzle input. Keep inputs small, so that the reference stays fast and mismatches
+    /// are easy to read.
+    fn generate(rng: &mut Rng) -> String;
+
+    fn reference_part_one(input: &str) -> Self::PartOne;
+
+    fn reference_part_two(input: &str) -> Self::PartTwo;
+
+    /// Whether an input is a valid puzzle input. Smaller inputs that are not valid are discarded
+    /// while minimizing a mismatch.
+    fn is_valid(_input: &str) -> bool {
+        true
+    }
+
+    /// Smaller variants of an input to minimize a mismatch with, most promising first.
+    fn shrink(input: &str) -> Vec<String> {
+        remove_lines(input)
+    }
+}
+
+/// Variants of an input without one chunk of lines each, from halves down to single lines.
+pub fn remove_lines(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    remove_chunks(&lines)
+        .into_iter()
+        .map(|lines| lines.join("\n"))
+        .collect()
+}
+
+/// Variants of a single-line input without one chunk of characters each, from halves down to
+/// single characters.
+pub fn remove_chars(input: &str) -> Vec<String> {
+    let chars: Vec<char> = input.trim_end().chars().collect();
+    remove_chunks(&chars)
+        .into_iter()
+        .map(|chars| chars.into_iter().collect())
+        .collect()
+}
+
+fn remove_chunks<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut variants = vec![];
+    let mut size = items.len() / 2;
+
+    while size > 0 {
+        for start in (0..items.len()).step_by(size) {
+            let end = (start + size).min(items.len());
+            variants.push([&items[..start], &items[end..]].concat());
+        }
+        size /= 2;
+    }
+
+    variants
+}
+
+/// Answer of a part, or the message it panicked with.
+pub type Outcome = Result<Option<String>, String>;
+
+/// The first generated input on which a solution and its reference disagree.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: u8,
+    /// Seed of the failing case, which generates its input again as the first case of a run.
+    pub seed: u64,
+    /// The generated input before it was minimized.
+    pub generated: String,
+    /// The smallest input found that still shows the mismatch.
+    pub input: String,
+    pub solution: Outcome,
+    pub reference: Outcome,
+}
+
+/// How many random inputs `cargo difftest` tries, and which of their parts it compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub cases: u32,
+    /// Seed of the first case. Every following case adds one to it.
+    pub seed: u64,
+    /// Only compare this part. Compares both parts if `None`.
+    pub part: Option<u8>,
+}
+
+fn outcome(part: impl FnOnce() -> Outcome) -> Outcome {
+    panic::catch_unwind(AssertUnwindSafe(part)).unwrap_or_else(|payload| {
+        Err(format!(
+            "panicked: {}",
+            runner::panic_message(payload.as_ref())
+        ))
+    })
+}
+
+/// Answer of a part of the solution. An input that can not be parsed fails the part.
+fn solution_outcome<S: Solution, T: Answer>(input: &str, part: fn(&S::Parsed) -> T) -> Outcome {
+    outcome(|| match S::parse(input) {
+        Ok(parsed) => part(&parsed).into_answer(),
+        Err(error) => Err(format!("could not parse input: {}", error)),
+    })
+}
+
+/// Answers of the solution and the reference for a part.
+fn outcomes<S: Reference>(input: &str, part: u8) -> (Outcome, Outcome) {
+    match part {
+        1 => (
+            solution_outcome::<S, _>(input, S::part_one),
+            outcome(|| S::reference_part_one(input).into_answer()),
+        ),
+        _ => (
+            solution_outcome::<S, _>(input, S::part_two),
+            outcome(|| S::reference_part_two(input).into_answer()),
+        ),
+    }
+}
+
+/// Whether two outcomes agree. Inputs that both implementations fail on are not compared, since
+/// there is no answer to compare.
+fn agree(solution: &Outcome, reference: &Outcome) -> bool {
+    solution == reference || (solution.is_err() && reference.is_err())
+}
+
+/// Shrinks `input` with [`Reference::shrink`] for as long as a valid smaller input still fails.
+pub fn minimize<S: Reference>(input: String, fails: impl Fn(&str) -> bool) -> String {
+    let mut input = input;
+
+    'shrink: loop {
+        for candidate in S::shrink(&input) {
+            if candidate.len() < input.len() && S::is_valid(&candidate) && fails(&candidate) {
+                input = candidate;
+                continue 'shrink;
+            }
+        }
+        return input;
+    }
+}
+
+/// Compares a solution against its reference on `options.cases` random inputs.
+/// Returns the number of inputs that agree, or the first mismatch with a minimized input.
+pub fn run<S: Reference>(options: Options) -> Result<u32, Box<Mismatch>> {
+    let parts: Vec<u8> = match options.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for case in 0..options.cases {
+        let seed = options.seed.wrapping_add(case as u64);
+        let input = S::generate(&mut Rng::new(seed));
+
+        for &part in &parts {
+            let (solution, reference) = outcomes::<S>(&input, part);
+            if agree(&solution, &reference) {
+                continue;
+            }
+
+            let minimized = minimize::<S>(input.clone(), |candidate| {
+                let (solution, reference) = outcomes::<S>(candidate, part);
+                !agree(&solution, &reference)
+            });
+            let (solution, reference) = outcomes::<S>(&minimized, part);
+
+            return Err(Box::new(Mismatch {
+                part,
+                seed,
+                generated: input,
+                input: minimized,
+                solution,
+                reference,
+            }));
+        }
+    }
+
+    Ok(options.cases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(7);
+        assert!((0..1000).all(|_| (3..=5).contains(&rng.range(3..=5))));
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+
+        let mut items: Vec<u8> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    /// Sums the numbers of the input, but the "optimized" version forgets numbers above 5.
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u32>;
+        type Error = std::num::ParseIntError;
+        type PartOne = Option<u32>;
+        type PartTwo = Option<u32>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+            input.lines().map(|line| line.parse()).collect()
+        }
+
+        fn part_one(numbers: &Self::Parsed) -> Option<u32> {
+            Some(numbers.iter().filter(|&&n| n <= 5).sum())
+        }
+
+        fn part_two(numbers: &Self::Parsed) -> Option<u32> {
+            Some(numbers.len() as u32)
+        }
+    }
+
+    impl Reference for Sum {
+        fn generate(rng: &mut Rng) -> String {
+            (0..20)
+                .map(|_| rng.range(0..=9).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+
+        fn reference_part_one(input: &str) -> Option<u32> {
+            Some(input.lines().map(|line| line.parse::<u32>().unwrap()).sum())
+        }
+
+        fn reference_part_two(input: &str) -> Option<u32> {
+            Some(input.lines().count() as u32)
+        }
+    }
+
+    #[test]
+    fn test_run_minimizes_mismatch() {
+        let options = Options {
+            cases: 10,
+            seed: 1,
+            part: None,
+        };
+        let mismatch = run::<Sum>(options).unwrap_err();
+
+        assert_eq!(mismatch.part, 1);
+        assert_eq!(mismatch.generated.lines().count(), 20);
+        assert_eq!(mismatch.input.lines().count(), 1);
+        assert!(mismatch.input.parse::<u32>().unwrap() > 5);
+
+        let options = Options {
+            part: Some(2),
+            ..options
+        };
+        assert_eq!(run::<Sum>(options), Ok(10));
+    }
+
+    #[test]
+    fn test_remove_chars() {
+        assert_eq!(
+            remove_chars("abcd\n"),
+            vec!["cd", "ab", "bcd", "acd", "abd", "abc"]
+        );
+    }
+}
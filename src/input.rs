@@ -0,0 +1,173 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Day binaries print this before the [`InputError`] that stopped them, see [`crate::read_input`].
+pub const LOAD_ERROR_PREFIX: &str = "Failed to load input: ";
+
+/// Why an input could not be loaded.
+#[derive(Debug)]
+pub enum Reason {
+    NotFound,
+    /// The file only contains whitespace, e.g. the placeholder created by `cargo scaffold`.
+    Empty,
+    Io(io::Error),
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    /// The resolved path of the file, or `-` for stdin.
+    pub path: PathBuf,
+    pub reason: Reason,
+    /// Year and day of puzzle inputs that `cargo download` can fetch.
+    pub download: Option<(u16, u8)>,
+    /// Whether the file is an example, which has to be copied from the puzzle description.
+    pub example: bool,
+}
+
+impl InputError {
+    fn new(path: &Path, reason: Reason) -> Self {
+        InputError {
+            path: path.into(),
+            reason,
+            download: None,
+            example: false,
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = self.path.display();
+        match &self.reason {
+            Reason::NotFound => write!(f, "input file \"{}\" does not exist", path)?,
+            Reason::Empty => write!(f, "input file \"{}\" is empty", path)?,
+            Reason::Io(e) if self.path == Path::new("-") => {
+                return write!(f, "could not read input from stdin: {}", e)
+            }
+            Reason::Io(e) => return write!(f, "could not read input file \"{}\": {}", path, e),
+        }
+
+        if let Some((year, day)) = self.download {
+            write!(
+                f,
+                ". Download it with `cargo download {} --year {}`.",
+                day, year
+            )
+        } else if self.example {
+            write!(f, ". Copy the example from the puzzle description into it.")
+        } else {
+            write!(f, ".")
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Reads a file, treating files that only contain whitespace as missing. `-` reads stdin.
+pub fn load_file(path: &Path) -> Result<String, InputError> {
+    let contents = read(path)?;
+    if contents.trim().is_empty() {
+        return Err(InputError::new(path, Reason::Empty));
+    }
+    Ok(contents)
+}
+
+/// Reads a file, or stdin for `-`.
+fn read(path: &Path) -> Result<String, InputError> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| input)
+            .map_err(|e| InputError::new(path, Reason::Io(e)))
+    } else {
+        fs::read_to_string(path).map_err(|e| {
+            let reason = match e.kind() {
+                io::ErrorKind::NotFound => Reason::NotFound,
+                _ => Reason::Io(e),
+            };
+            InputError::new(path, reason)
+        })
+    }
+}
+
+/// Reads `src/<year>/<folder>/<day>.txt`, e.g. the input or the example of a day.
+/// Only inputs have to contain something. Examples may still be the empty file created by
+/// `cargo scaffold`, so that the generated unit tests run before the example is filled in.
+pub fn load(folder: &str, year: u16, day: u8) -> Result<String, InputError> {
+    load_from(&crate::file_path(folder, year, day), folder, year, day)
+}
+
+fn load_from(path: &Path, folder: &str, year: u16, day: u8) -> Result<String, InputError> {
+    let example = folder == "examples";
+    let contents = if example { read(path) } else { load_file(path) };
+
+    contents.map_err(|e| InputError {
+        download: (folder == "inputs").then_some((year, day)),
+        example,
+        ..e
+    })
+}
+
+/// Reads the input of a day binary from the file or stdin passed to `--input`, see
+/// [`crate::input_override`], or else from `src/<year>/inputs/<day>.txt`.
+pub fn load_input(year: u16, day: u8, path: Option<&str>) -> Result<String, InputError> {
+    match path {
+        Some(path) => load_file(Path::new(path)),
+        None => load("inputs", year, day),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_load_file() {
+        let path = env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+
+        fs::write(&path, "1\n2\n").unwrap();
+        assert_eq!(load_file(&path).unwrap(), "1\n2\n");
+
+        fs::write(&path, "\n").unwrap();
+        assert!(matches!(load_file(&path), Err(e) if matches!(e.reason, Reason::Empty)));
+
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(load_file(&path), Err(e) if matches!(e.reason, Reason::NotFound)));
+    }
+
+    #[test]
+    fn test_load_empty_example() {
+        let path = env::temp_dir().join(format!("aoc-example-{}.txt", std::process::id()));
+        fs::write(&path, "").unwrap();
+
+        assert_eq!(load_from(&path, "examples", 2022, 1).unwrap(), "");
+        let error = load_from(&path, "inputs", 2022, 1).unwrap_err();
+        assert!(matches!(error.reason, Reason::Empty));
+        assert_eq!(error.download, Some((2022, 1)));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_error_message() {
+        let mut error = InputError::new(Path::new("src/2022/inputs/01.txt"), Reason::Empty);
+        assert_eq!(
+            error.to_string(),
+            "input file \"src/2022/inputs/01.txt\" is empty."
+        );
+
+        error.download = Some((2022, 1));
+        assert_eq!(
+            error.to_string(),
+            "input file \"src/2022/inputs/01.txt\" is empty. Download it with `cargo download 1 --year 2022`."
+        );
+    }
+}
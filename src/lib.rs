@@ -4,71 +4,140 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
-use std::fs;
-
+use std::path::{Path, PathBuf};
+use std::process;
+
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod dashboard;
+pub mod days;
+#[cfg(any(test, feature = "difftest"))]
+pub mod differential;
 pub mod helpers;
-
-pub const ANSI_ITALIC: &str = "\x1b[3m";
-pub const ANSI_BOLD: &str = "\x1b[1m";
-pub const ANSI_RESET: &str = "\x1b[0m";
+pub mod input;
+pub mod limits;
+pub mod log;
+pub mod memory;
+pub mod readme;
+pub mod report;
+pub mod runner;
+pub mod solution;
+pub mod style;
+pub mod visualize;
+
+// escape sequences live in `style`, re-exported here for code that used them from the crate root.
+pub use style::{ANSI_BOLD, ANSI_GREEN, ANSI_INVERSE, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+
+/// Environment variable that selects the default year of `cargo scaffold`, `cargo download` and `cargo all`.
+/// Set in `.cargo/config`.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// Argument of the day binaries that reads the input from a file, or from stdin if it is `-`.
+pub const INPUT_ARG: &str = "--input";
 
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+    ($solution:ty, $input:expr) => {{
+        // solution binaries are named after their year and day, e.g. `2022-01`.
+        let (year, day) = advent_of_code::parse_bin_name(env!("CARGO_BIN_NAME")).unwrap_or((0, 0));
+        advent_of_code::runner::run_day::<$solution>(year, day, $input);
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
+/// The year set in `AOC_YEAR`, if any.
+pub fn default_year() -> Option<u16> {
+    env::var(YEAR_ENV).ok()?.trim().parse().ok()
+}
+
+/// Root directory of the crate, so that binaries find their files no matter where they are started from.
+/// This is the directory the crate was built in or, if that is gone, the closest parent of the
+/// working directory with a `Cargo.toml`.
+pub fn crate_root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    if manifest_dir.join("Cargo.toml").is_file() {
+        return manifest_dir.to_path_buf();
+    }
     let cwd = env::current_dir().unwrap();
+    find_crate_root(&cwd).unwrap_or(cwd)
+}
+
+fn find_crate_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Directory that holds the inputs, examples and recorded answers of a year, e.g. `src/2022`.
+pub fn year_dir(year: u16) -> PathBuf {
+    crate_root().join("src").join(year.to_string())
+}
+
+pub fn file_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(folder).join(format!("{:02}.txt", day))
+}
+
+/// Reads `src/<year>/<folder>/<day>.txt`, panicking if it is missing. Inputs must not be empty, examples
+/// may be. Meant for unit tests, see [`input::load`] for a loader that returns an error instead.
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    input::load(folder, year, day).unwrap_or_else(|e| panic!("{}", e))
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// The file passed to `--input`, if any. `-` stands for stdin.
+/// Returns an error if `--input` is given without a file.
+pub fn input_override() -> Result<Option<String>, pico_args::Error> {
+    pico_args::Arguments::from_env().opt_value_from_str(INPUT_ARG)
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Reads the input of a day binary, see [`input::load_input`].
+/// Prints the error and exits the process if `--input` is invalid or the input can not be loaded.
+pub fn read_input(year: u16, day: u8) -> String {
+    let path = input_override().unwrap_or_else(|e| {
+        eprintln!("Failed to process arguments: {}", e);
+        process::exit(1);
+    });
+    input::load_input(year, day, path.as_deref()).unwrap_or_else(|e| {
+        eprintln!("{}{}", input::LOAD_ERROR_PREFIX, e);
+        process::exit(1);
+    })
+}
+
+/// Name of the binary of a day, e.g. `2022-01`.
+pub fn bin_name(year: u16, day: u8) -> String {
+    format!("{}-{:02}", year, day)
+}
+
+/// Parses a binary name written by [`bin_name`] into its year and day.
+pub fn parse_bin_name(name: &str) -> Option<(u16, u8)> {
+    let (year, day) = name.split_once('-')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
 }
 
 fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+    val.strip_suffix(postfix)
+        .and_then(|val| val.parse().ok())
+        .unwrap_or(0_f64)
 }
 
+/// Sums the `(elapsed: …)` timings in the text output of day binaries in milliseconds.
+/// Timings are exchanged as nanoseconds in `jsonl` output, see [`report::Format`]. This is only a
+/// fallback for binaries that print plain text, since it depends on the `Debug` format of `Duration`.
 pub fn parse_exec_time(output: &str) -> f64 {
     output.lines().fold(0_f64, |acc, l| {
         if !l.contains("elapsed:") {
             acc
         } else {
             let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
+            // the timing may be followed by more statistics, a closing parenthesis and ANSI escape sequences.
+            let timing = timing.split([',', ')']).next().unwrap().trim();
             // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
+            if timing.ends_with("ns") {
                 acc // range below rounding precision.
-            } else if timing.contains("µs)") {
+            } else if timing.ends_with("µs") {
                 acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
+            } else if timing.ends_with("ms") {
                 acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
+            } else if timing.ends_with('s') {
                 acc + parse_time(timing, "s") * 1000_f64
             } else {
                 acc
@@ -95,6 +164,21 @@ macro_rules! assert_approx_eq {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_bin_name() {
+        assert_eq!(bin_name(2022, 1), "2022-01");
+        assert_eq!(parse_bin_name("2022-01"), Some((2022, 1)));
+        assert_eq!(parse_bin_name("01"), None);
+        assert_eq!(parse_bin_name("scaffold"), None);
+    }
+
+    #[test]
+    fn test_find_crate_root() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(find_crate_root(&root.join("src/days")), Some(root.into()));
+        assert_eq!(find_crate_root(root), Some(root.into()));
+    }
+
     #[test]
     fn test_parse_exec_time() {
         assert_approx_eq!(
@@ -121,5 +205,12 @@ mod tests {
             ),
             10400.50_f64
         );
+
+        assert_approx_eq!(
+            parse_exec_time(
+                "0 (elapsed: 1.20ms, allocations: 10, allocated: 2.06 KiB)\n0 (elapsed: ??)"
+            ),
+            1.2_f64
+        );
     }
 }
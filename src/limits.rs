@@ -0,0 +1,224 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::io::{self, Read};
+use std::process::{Child, Output};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Environment variable that passes the memory limit in megabytes on to day binaries.
+pub const MEMORY_LIMIT_ENV: &str = "AOC_MEMORY_LIMIT";
+
+/// How often a running child process is checked for completion while a timeout is set.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Takes the memory limit in megabytes from `--memory-limit <mb>` out of `args`, falling back to
+/// `AOC_MEMORY_LIMIT`. Returns an error if either of them is not a number.
+pub fn memory_limit_from_args(
+    args: &mut pico_args::Arguments,
+) -> Result<Option<u64>, pico_args::Error> {
+    if let Some(megabytes) = args.opt_value_from_str("--memory-limit")? {
+        return Ok(Some(megabytes));
+    }
+    match env::var(MEMORY_LIMIT_ENV) {
+        Ok(value) => parse_memory_limit(&value).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
+fn parse_memory_limit(value: &str) -> Result<u64, pico_args::Error> {
+    value
+        .trim()
+        .parse()
+        .map_err(|e| pico_args::Error::Utf8ArgumentParsingFailed {
+            value: value.to_string(),
+            cause: format!("invalid {}: {}", MEMORY_LIMIT_ENV, e),
+        })
+}
+
+/// Applies a memory limit read by [`memory_limit_from_args`] to this process, if one is set.
+pub fn apply_memory_limit(limit: Option<u64>) {
+    if let Some(megabytes) = limit {
+        if let Err(e) = set_memory_limit(megabytes) {
+            eprintln!("Failed to set memory limit: {}", e);
+        }
+    }
+}
+
+/// Limits the heap memory of this process to `megabytes`. Allocations beyond the limit abort the process.
+/// Only the soft limit is set, capped at the current hard limit, so the limit can be raised again later.
+#[cfg(target_os = "linux")]
+pub fn set_memory_limit(megabytes: u64) -> io::Result<()> {
+    let bytes = megabytes.saturating_mul(1024 * 1024) as sys::rlim_t;
+    let mut limit = sys::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+
+    if unsafe { sys::getrlimit(sys::RLIMIT_DATA, &mut limit) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    limit.rlim_cur = bytes.min(limit.rlim_max);
+
+    match unsafe { sys::setrlimit(sys::RLIMIT_DATA, &limit) } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn set_memory_limit(_megabytes: u64) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "memory limits are only supported on Linux",
+    ))
+}
+
+/// Waits for `child` to exit and collects its output. Returns `None` if it did not exit within `timeout`,
+/// in which case it is killed along with its process group.
+pub fn wait_with_timeout(
+    mut child: Child,
+    timeout: Option<Duration>,
+) -> io::Result<Option<Output>> {
+    let Some(timeout) = timeout else {
+        return child.wait_with_output().map(Some);
+    };
+
+    // read output in the background so a full pipe can not block the child.
+    let stdout = child.stdout.take().map(read_in_background);
+    let stderr = child.stderr.take().map(read_in_background);
+    let deadline = Instant::now() + timeout;
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            kill(&mut child)?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    let collect = |handle: Option<JoinHandle<Vec<u8>>>| {
+        handle.map_or_else(Vec::new, |handle| handle.join().unwrap_or_default())
+    };
+
+    Ok(Some(Output {
+        status,
+        stdout: collect(stdout),
+        stderr: collect(stderr),
+    }))
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = vec![];
+        let _ = pipe.read_to_end(&mut buffer);
+        buffer
+    })
+}
+
+/// Kills the process group of `child`. `cargo run` does not forward signals to the binary it runs,
+/// so children are spawned in their own process group and the whole group is killed.
+#[cfg(unix)]
+fn kill(child: &mut Child) -> io::Result<()> {
+    match unsafe { sys::kill(-(child.id() as i32), sys::SIGKILL) } {
+        0 => Ok(()),
+        _ => child.kill(),
+    }
+}
+
+#[cfg(not(unix))]
+fn kill(child: &mut Child) -> io::Result<()> {
+    child.kill()
+}
+
+#[cfg(unix)]
+#[allow(non_camel_case_types)]
+mod sys {
+    use std::ffi::c_int;
+
+    pub const SIGKILL: c_int = 9;
+
+    extern "C" {
+        pub fn kill(pid: c_int, sig: c_int) -> c_int;
+    }
+
+    #[cfg(target_os = "linux")]
+    pub type rlim_t = std::ffi::c_ulong;
+
+    #[cfg(target_os = "linux")]
+    pub const RLIMIT_DATA: c_int = 2;
+
+    #[cfg(target_os = "linux")]
+    #[repr(C)]
+    pub struct rlimit {
+        pub rlim_cur: rlim_t,
+        pub rlim_max: rlim_t,
+    }
+
+    #[cfg(target_os = "linux")]
+    extern "C" {
+        pub fn getrlimit(resource: c_int, rlim: *mut rlimit) -> c_int;
+        pub fn setrlimit(resource: c_int, rlim: *const rlimit) -> c_int;
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::process::CommandExt;
+    use std::process::{Command, Stdio};
+
+    #[test]
+    fn test_parse_memory_limit() {
+        assert_eq!(parse_memory_limit("512").unwrap(), 512);
+        assert!(parse_memory_limit("512mb").is_err());
+    }
+
+    #[test]
+    fn test_wait_with_timeout() {
+        let spawn = |seconds: &str| {
+            Command::new("sleep")
+                .arg(seconds)
+                .stdout(Stdio::piped())
+                .process_group(0)
+                .spawn()
+                .unwrap()
+        };
+
+        let timer = Instant::now();
+        let output = wait_with_timeout(spawn("10"), Some(Duration::from_millis(50))).unwrap();
+        assert!(output.is_none());
+        assert!(timer.elapsed() < Duration::from_secs(5));
+
+        let output = wait_with_timeout(spawn("0"), Some(Duration::from_secs(5))).unwrap();
+        assert!(output.unwrap().status.success());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_set_memory_limit_keeps_hard_limit() {
+        let get = || {
+            let mut limit = sys::rlimit {
+                rlim_cur: 0,
+                rlim_max: 0,
+            };
+            assert_eq!(unsafe { sys::getrlimit(sys::RLIMIT_DATA, &mut limit) }, 0);
+            limit
+        };
+        let before = get();
+
+        // 1 TiB, far above what the other tests allocate.
+        set_memory_limit(1024 * 1024).unwrap();
+        let after = get();
+        assert_eq!(after.rlim_max, before.rlim_max);
+        assert_eq!(after.rlim_cur, (1 << 40).min(before.rlim_max));
+
+        assert_eq!(unsafe { sys::setrlimit(sys::RLIMIT_DATA, &before) }, 0);
+    }
+}
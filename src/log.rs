@@ -0,0 +1,325 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt;
+use std::io::{self, Write};
+use std::mem;
+use std::str::FromStr;
+use std::sync::{Mutex, PoisonError};
+
+use once_cell::sync::Lazy;
+
+/// Whether log calls are compiled in. Release builds leave them out, so that benchmarks are not affected.
+pub const ENABLED: bool = cfg!(debug_assertions);
+
+/// Verbosity of all days and of single days, e.g. `debug` or `info,7=trace,2021-03=debug`.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+
+    /// Parses a level name or number. `off` and `0` turn logging off.
+    pub fn parse(level: &str) -> Result<Option<Self>, String> {
+        match level.trim().to_ascii_lowercase().as_str() {
+            "off" | "0" => Ok(None),
+            "info" | "1" => Ok(Some(Level::Info)),
+            "debug" | "2" => Ok(Some(Level::Debug)),
+            "trace" | "3" => Ok(Some(Level::Trace)),
+            _ => Err(format!(
+                "invalid log level \"{}\", expected off, info, debug or trace",
+                level
+            )),
+        }
+    }
+
+    fn from_verbosity(count: usize) -> Option<Self> {
+        match count {
+            0 => None,
+            1 => Some(Level::Info),
+            2 => Some(Level::Debug),
+            _ => Some(Level::Trace),
+        }
+    }
+}
+
+/// Which messages are logged.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Filter {
+    /// Level of days without a level of their own.
+    pub default: Option<Level>,
+    /// Levels of single days, with the year if one was given.
+    pub days: Vec<(Option<u16>, u8, Option<Level>)>,
+}
+
+impl Filter {
+    /// Parses a comma-separated list of a default level and `<day>=<level>` or `<year>-<day>=<level>` items.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut filter = Filter::default();
+
+        for item in spec.split(',').filter(|item| !item.trim().is_empty()) {
+            match item.split_once('=') {
+                Some((day, level)) => {
+                    let (year, day) = match crate::parse_bin_name(day.trim()) {
+                        Some((year, day)) => (Some(year), day),
+                        None => (
+                            None,
+                            day.trim()
+                                .parse()
+                                .map_err(|_| format!("invalid day \"{}\"", day))?,
+                        ),
+                    };
+                    filter.days.push((year, day, Level::parse(level)?));
+                }
+                None => filter.default = Level::parse(item)?,
+            }
+        }
+
+        Ok(filter)
+    }
+
+    /// Reads `AOC_LOG` and `-v`, `-vv` or `-vvv`, which raise the default level to info, debug or trace.
+    pub fn from_env() -> Self {
+        let mut filter = match env::var(LOG_ENV) {
+            Ok(spec) => Filter::parse(&spec).unwrap_or_else(|e| {
+                eprintln!("Ignoring {}: {}", LOG_ENV, e);
+                Filter::default()
+            }),
+            Err(_) => Filter::default(),
+        };
+
+        let verbosity: usize = env::args()
+            .skip(1)
+            .map(|arg| match arg.as_str() {
+                "--verbose" => 1,
+                arg if arg.len() > 1
+                    && arg.starts_with('-')
+                    && arg[1..].bytes().all(|b| b == b'v') =>
+                {
+                    arg.len() - 1
+                }
+                _ => 0,
+            })
+            .sum();
+        filter.default = filter.default.max(Level::from_verbosity(verbosity));
+        filter
+    }
+
+    /// Whether a message of `level` from a day is logged. The last matching day item wins.
+    pub fn enabled(&self, year: Option<u16>, day: Option<u8>, level: Level) -> bool {
+        let day_level = self.days.iter().rev().find(|(item_year, item_day, _)| {
+            Some(*item_day) == day && item_year.is_none_or(|item_year| Some(item_year) == year)
+        });
+
+        match day_level {
+            Some((_, _, max)) => max.is_some_and(|max| level <= max),
+            None => self.default.is_some_and(|max| level <= max),
+        }
+    }
+}
+
+/// Takes `-v`, `-vv`, `-vvv` and `--verbose` out of `args`, since they are read by [`Filter::from_env`] instead.
+pub fn remove_verbosity_args(args: &mut pico_args::Arguments) {
+    for flag in ["-v", "-vv", "-vvv", "--verbose"] {
+        while args.contains(flag) {}
+    }
+}
+
+/// Year and day of a solution module path, e.g. `advent_of_code::days::y2022::day07`.
+pub fn parse_module_path(path: &str) -> (Option<u16>, Option<u8>) {
+    (module_number(path, "y"), module_number(path, "day"))
+}
+
+/// Year and day of a solution source file, e.g. `src/days/y2022/day07.rs`.
+pub fn parse_file_path(path: &str) -> (Option<u16>, Option<u8>) {
+    parse_module_path(&path.trim_end_matches(".rs").replace(['/', '\\'], "::"))
+}
+
+fn module_number<T: FromStr>(path: &str, prefix: &str) -> Option<T> {
+    path.split("::")
+        .find_map(|segment| segment.strip_prefix(prefix)?.parse().ok())
+}
+
+static FILTER: Lazy<Filter> = Lazy::new(Filter::from_env);
+
+/// Whether a message of `level` from the module at `module_path` is logged.
+pub fn enabled(module_path: &str, level: Level) -> bool {
+    let (year, day) = parse_module_path(module_path);
+    FILTER.enabled(year, day, level)
+}
+
+/// A line collected instead of written to stderr, see [`capture`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captured {
+    /// Year and day of the solution the line came from, if known.
+    pub day: Option<(u16, u8)>,
+    pub line: String,
+}
+
+static CAPTURED: Mutex<Option<Vec<Captured>>> = Mutex::new(None);
+
+/// Starts or stops collecting log lines instead of writing them to stderr, e.g. while the
+/// dashboard covers the terminal. Returns the lines that were collected but not taken yet.
+pub fn capture(enabled: bool) -> Vec<Captured> {
+    let mut captured = CAPTURED.lock().unwrap_or_else(PoisonError::into_inner);
+    let lines = captured.take().unwrap_or_default();
+    *captured = enabled.then(Vec::new);
+    lines
+}
+
+/// Takes the lines collected since the last call, see [`capture`].
+pub fn take_captured() -> Vec<Captured> {
+    CAPTURED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_mut()
+        .map(mem::take)
+        .unwrap_or_default()
+}
+
+/// Writes a line like `[DEBUG 2022-07 src/days/y2022/day07.rs:42] 14 directories` to stderr,
+/// or collects it while [`capture`] is enabled.
+pub fn emit(
+    label: &str,
+    (year, day): (Option<u16>, Option<u8>),
+    file: &str,
+    line: u32,
+    message: impl fmt::Display,
+) {
+    let day = year.zip(day);
+    let name = day.map_or_else(String::new, |(year, day)| {
+        format!(" {}", crate::bin_name(year, day))
+    });
+    let line = format!("[{}{} {}:{}] {}", label, name, file, line, message);
+
+    let mut captured = CAPTURED.lock().unwrap_or_else(PoisonError::into_inner);
+    match captured.as_mut() {
+        Some(lines) => lines.push(Captured { day, line }),
+        None => {
+            let _ = writeln!(io::stderr().lock(), "{}", line);
+        }
+    }
+}
+
+/// Writes a log line, see [`emit`].
+/// Use the [`info!`](crate::info), [`debug!`](crate::debug) and [`trace!`](crate::trace) macros instead.
+pub fn write(module_path: &str, file: &str, line: u32, level: Level, args: fmt::Arguments) {
+    emit(
+        level.name(),
+        parse_module_path(module_path),
+        file,
+        line,
+        args,
+    );
+}
+
+/// Logs a message at a [`Level`] to stderr if the day's verbosity allows it.
+/// The message is not formatted if it is not logged, and the call is compiled out of release builds.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        if $crate::log::ENABLED && $crate::log::enabled(module_path!(), $level) {
+            $crate::log::write(module_path!(), file!(), line!(), $level, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filter() {
+        let filter = Filter::parse("info, 7=trace,2021-03=off").unwrap();
+        assert_eq!(filter.default, Some(Level::Info));
+        assert_eq!(
+            filter.days,
+            vec![(None, 7, Some(Level::Trace)), (Some(2021), 3, None)]
+        );
+        assert!(Filter::parse("loud").is_err());
+        assert!(Filter::parse("x=debug").is_err());
+    }
+
+    #[test]
+    fn test_filter_enabled() {
+        let filter = Filter::parse("info,7=trace,2021-03=off").unwrap();
+        assert!(filter.enabled(Some(2022), Some(7), Level::Trace));
+        assert!(filter.enabled(Some(2022), Some(3), Level::Info));
+        assert!(!filter.enabled(Some(2022), Some(3), Level::Debug));
+        assert!(!filter.enabled(Some(2021), Some(3), Level::Info));
+        assert!(!Filter::default().enabled(None, None, Level::Info));
+    }
+
+    #[test]
+    fn test_capture() {
+        capture(true);
+        emit(
+            "INFO",
+            (Some(2022), Some(7)),
+            "src/days/y2022/day07.rs",
+            42,
+            "captured",
+        );
+        let captured = capture(false);
+        assert!(captured.contains(&Captured {
+            day: Some((2022, 7)),
+            line: "[INFO 2022-07 src/days/y2022/day07.rs:42] captured".into(),
+        }));
+    }
+
+    #[test]
+    fn test_parse_file_path() {
+        assert_eq!(
+            parse_file_path("src/days/y2022/day07.rs"),
+            (Some(2022), Some(7))
+        );
+        assert_eq!(parse_file_path("src/main.rs"), (None, None));
+    }
+
+    #[test]
+    fn test_remove_verbosity_args() {
+        let mut args = pico_args::Arguments::from_vec(
+            ["-v", "-vv", "--verbose", "-v"].map(Into::into).to_vec(),
+        );
+        remove_verbosity_args(&mut args);
+        assert!(args.finish().is_empty());
+    }
+
+    #[test]
+    fn test_parse_module_path() {
+        assert_eq!(
+            parse_module_path("advent_of_code::days::y2022::day07"),
+            (Some(2022), Some(7))
+        );
+        assert_eq!(parse_module_path("advent_of_code::runner"), (None, None));
+    }
+}
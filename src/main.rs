@@ -2,45 +2,343 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::Check;
+use advent_of_code::baseline::{self, DEFAULT_THRESHOLD};
+use advent_of_code::bench::BenchOptions;
+use advent_of_code::dashboard;
+use advent_of_code::days;
+use advent_of_code::limits;
+use advent_of_code::log;
+use advent_of_code::memory::{self, AllocStats};
+use advent_of_code::readme::{self, README_FILE};
+use advent_of_code::report::{print_parse, print_part_header, Format, PartReport};
+use advent_of_code::runner::{self, DayRun, DayStatus, RunOptions};
+use advent_of_code::style::{self, bold, italic};
+use std::process;
+use std::time::{Duration, Instant};
+
+struct Args {
+    /// Year to run. Defaults to `AOC_YEAR`, or the latest year with a registered solution.
+    year: Option<u16>,
+    /// Spawn `cargo run --release --bin <year>-<day>` for every day instead of calling solutions in-process.
+    subprocess: bool,
+    /// Run one day at a time. Use this for timing-sensitive benchmark runs.
+    sequential: bool,
+    /// Number of worker threads for parallel runs. Defaults to the number of CPU cores.
+    jobs: Option<usize>,
+    /// Store the answers of all solved parts as the new expected answers.
+    record: bool,
+    /// Days to run, e.g. `3-7,9`. Defaults to all days.
+    days: Option<Vec<u8>>,
+    /// Only run this part of each day.
+    part: Option<u8>,
+    /// Leave out days without a solution instead of reporting them as not solved.
+    skip_unsolved: bool,
+    /// Give up on days that take longer than this.
+    timeout: Option<Duration>,
+    /// Store the timings of all solved parts as the new baseline.
+    save_baseline: bool,
+    /// Slowdown compared to the baseline in percent above which a part is flagged.
+    threshold: f64,
+    format: Format,
+    /// Memory limit in megabytes, see [`limits::memory_limit_from_args`].
+    memory_limit: Option<u64>,
+    /// Benchmark every part, see [`BenchOptions::from_args`].
+    bench: Option<BenchOptions>,
+    /// Render a benchmark table into the readme. Implies `--bench` and `--sequential`.
+    readme: bool,
+    /// Show an interactive grid of all days instead of printing their results.
+    dashboard: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args.opt_value_from_fn(["-y", "--year"], runner::parse_year)?,
+        subprocess: args.contains("--subprocess"),
+        sequential: args.contains("--sequential"),
+        jobs: args.opt_value_from_str(["-j", "--jobs"])?,
+        record: args.contains("--record"),
+        days: args.opt_value_from_fn("--days", runner::parse_days)?,
+        part: args.opt_value_from_fn("--part", runner::parse_part)?,
+        skip_unsolved: args.contains("--skip-unsolved"),
+        timeout: args.opt_value_from_fn("--timeout", runner::parse_timeout)?,
+        save_baseline: args.contains("--save-baseline"),
+        threshold: args
+            .opt_value_from_fn("--threshold", baseline::parse_threshold)?
+            .unwrap_or(DEFAULT_THRESHOLD),
+        format: Format::from_args(&mut args)?,
+        memory_limit: limits::memory_limit_from_args(&mut args)?,
+        bench: BenchOptions::from_args(&mut args)?,
+        readme: args.contains("--readme"),
+        dashboard: args.contains("--dashboard"),
+    };
+    style::remove_color_arg(&mut args)?;
+    log::remove_verbosity_args(&mut args);
+    runner::reject_unknown_args(args)?;
+    Ok(parsed)
+}
+
+/// Whether a part got slower than its baseline by more than `threshold` percent.
+fn is_slower(report: &PartReport, threshold: f64) -> bool {
+    report.change().is_some_and(|change| change > threshold)
+}
+
+fn print_day(run: &DayRun, format: Format, threshold: f64) {
+    if format == Format::JsonLines {
+        if let Some(parse) = run.parse {
+            print_parse(run.day, parse, format);
+        }
+        run.reports.iter().for_each(|report| report.print(format));
+        return;
+    }
+
+    println!("----------");
+    println!("{}", bold(format!("| Day {:02} |", run.day)));
+    println!("----------");
+
+    if let Some(parse) = run.parse {
+        print_parse(run.day, parse, format);
+    }
+    // days that panicked or crashed may have finished some parts before.
+    for report in &run.reports {
+        print_part_header(report.part);
+        report.print(format);
+        report.print_error();
+        if is_slower(report, threshold) {
+            println!(
+                "{} than baseline by more than {}%.",
+                bold("Slower"),
+                threshold
+            );
+        }
+    }
+
+    match &run.status {
+        DayStatus::Ran => {}
+        DayStatus::NotSolved => println!("Not solved."),
+        DayStatus::MissingInput(error) => println!("Input missing: {}", error),
+        // binary does not emit structured output, print it as-is.
+        DayStatus::Unstructured(output) => println!("{}", output.trim()),
+        DayStatus::TimedOut(timeout) => println!("Timed out after {:.2?}.", timeout),
+        DayStatus::BuildFailed(error) => println!("Build failed: {}", error),
+        DayStatus::Panicked(message) => println!("Panicked: {}", message),
+        DayStatus::Crashed(status) => println!("Crashed ({}).", status),
+    }
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    // the table is rendered from the runs alone, a subset would drop the other rows.
+    if args.readme && (args.days.is_some() || args.part.is_some()) {
+        eprintln!("`--readme` renders a table of all days and parts and can not be combined with `--days` or `--part`.");
+        process::exit(1);
+    }
+    // in-process days run in the build of the runner, day binaries always run as release builds.
+    if cfg!(debug_assertions) && !args.subprocess && (args.readme || args.save_baseline) {
+        eprintln!(
+            "{} writing timings of a debug build. Use `cargo all --release` for timings worth comparing.",
+            bold("Warning:")
+        );
+    }
+    let year = match args
+        .year
+        .or_else(advent_of_code::default_year)
+        .or_else(days::latest_year)
+    {
+        Some(year) => year,
+        None => {
+            eprintln!("Need to specify a year with `--year` or `AOC_YEAR`.");
+            process::exit(1);
+        }
+    };
+    let format = args.format;
+    let mut answers = runner::load_answers(year, args.record);
+    let mut baseline = runner::load_baseline(year, args.save_baseline);
+    let options = RunOptions {
+        part: args.part,
+        timeout: args.timeout,
+        memory_limit: args.memory_limit,
+        bench: match args.bench {
+            None if args.readme => Some(BenchOptions::default()),
+            bench => bench,
+        },
+    };
+
+    if args.subprocess {
+        if args.timeout.is_some() {
+            runner::build_release();
+        }
+    } else {
+        // in-process days share the memory of the runner.
+        limits::apply_memory_limit(args.memory_limit);
+    }
+
+    let run_day_with = |day, options: RunOptions| {
+        let mut run = if args.subprocess {
+            runner::run_in_subprocess(year, day, options)
+        } else {
+            runner::run_in_process(year, day, options)
+        };
+        for report in &mut run.reports {
+            report.expected = answers.get(report.day, report.part).map(String::from);
+            report.baseline = baseline.get(report.day, report.part);
+        }
+        run
+    };
+    let run_day = |day| run_day_with(day, options);
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+    let days: Vec<u8> = args
+        .days
+        .clone()
+        .unwrap_or_else(|| (1..=25).collect())
+        .into_iter()
+        .filter(|&day| !args.skip_unsolved || runner::is_implemented(year, day))
+        .collect();
+    let jobs = args.jobs.unwrap_or_else(runner::default_jobs);
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    if args.dashboard {
+        if let Err(e) = dashboard::run(year, &days, jobs, options, run_day_with) {
+            eprintln!("Failed to run the dashboard: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    let timer = Instant::now();
+
+    // days running side by side would slow down each other's benchmarks.
+    let runs: Vec<DayRun> = if args.sequential || args.readme {
+        days.iter()
+            .map(|&day| {
+                let run = run_day(day);
+                print_day(&run, format, args.threshold);
+                run
+            })
+            .collect()
+    } else {
+        let runs = runner::run_parallel(&days, jobs, run_day);
+        runs.iter()
+            .for_each(|run| print_day(run, format, args.threshold));
+        runs
+    };
+
+    let wall_clock = timer.elapsed();
+    let total: Duration = runs.iter().map(DayRun::duration).sum();
+    let failed = runs
+        .iter()
+        .flat_map(|run| &run.reports)
+        .filter(|report| report.is_failed())
+        .count();
+    let allocs: Vec<AllocStats> = runs
+        .iter()
+        .flat_map(|run| &run.reports)
+        .filter_map(|report| report.allocs)
+        .collect();
+    let timed_out = runs
+        .iter()
+        .filter(|run| matches!(run.status, DayStatus::TimedOut(_)))
+        .count();
+    let broken: Vec<&DayRun> = runs
+        .iter()
+        .filter(|run| {
+            matches!(
+                run.status,
+                DayStatus::BuildFailed(_) | DayStatus::Panicked(_) | DayStatus::Crashed(_)
+            )
+        })
+        .collect();
+    let slower = runs
+        .iter()
+        .flat_map(|run| &run.reports)
+        .filter(|report| is_slower(report, args.threshold))
+        .count();
+    let regressions = runs
+        .iter()
+        .flat_map(|run| &run.reports)
+        .filter(|report| report.check() == Check::Regression)
+        .count();
 
+    if format == Format::Text {
+        println!(
+            "{} {}",
+            bold("Total:"),
+            italic(format!("{:.2}ms", total.as_secs_f64() * 1000_f64))
+        );
+        println!(
+            "{} {}",
+            bold("Wall-clock:"),
+            italic(format!("{:.2}ms", wall_clock.as_secs_f64() * 1000_f64))
+        );
+        if !allocs.is_empty() {
             println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
+                "{} {}",
+                bold("Allocations:"),
+                italic(format!(
+                    "{} ({} allocated, highest peak: {})",
+                    allocs.iter().map(|a| a.allocations).sum::<u64>(),
+                    memory::format_bytes(allocs.iter().map(|a| a.bytes).sum()),
+                    memory::format_bytes(allocs.iter().map(|a| a.peak).max().unwrap_or(0))
+                ))
             );
+        }
+        if failed > 0 {
+            println!("{} {} part(s)", bold("Failed:"), failed);
+        }
+        if timed_out > 0 {
+            println!("{} {} day(s)", bold("Timed out:"), timed_out);
+        }
+        for run in &broken {
+            let (label, message) = match &run.status {
+                DayStatus::BuildFailed(error) => ("Build failed", error),
+                DayStatus::Panicked(message) => ("Panicked", message),
+                DayStatus::Crashed(status) => ("Crashed", status),
+                _ => unreachable!(),
+            };
+            println!(
+                "{} Day {:02}: {}",
+                bold(format!("{}:", label)),
+                run.day,
+                message
+            );
+        }
+        if slower > 0 {
+            println!("{} {} part(s)", bold("Slower than baseline:"), slower);
+        }
+        if regressions > 0 {
+            println!("{} {} part(s)", bold("Regressions:"), regressions);
+        }
+    }
+
+    if args.record {
+        runner::record_answers(&mut answers, year, runs.iter().flat_map(|run| &run.reports));
+    }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    if args.readme {
+        match readme::update(year, runs.iter().flat_map(|run| &run.reports)) {
+            Ok(_) => eprintln!("Updated benchmarks in {}.", README_FILE),
+            Err(e) => {
+                eprintln!("Failed to update {}: {}", README_FILE, e);
+                process::exit(1);
             }
-        })
-        .sum();
+        }
+    }
+
+    if args.save_baseline {
+        runner::save_baseline(
+            &mut baseline,
+            year,
+            runs.iter().flat_map(|run| &run.reports),
+        );
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if failed > 0 || timed_out > 0 || !broken.is_empty() || (regressions > 0 && !args.record) {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,121 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::Duration;
+use std::{fs, io};
+
+use crate::answers::Check;
+use crate::report::PartReport;
+
+pub const README_FILE: &str = "README.md";
+
+/// Marks the start and the end of the benchmark section of a year in the readme.
+pub fn marker(year: u16) -> String {
+    format!("<!--- {} benchmarking table --->", year)
+}
+
+/// Renders the benchmark section of a year including its markers, with one row per part.
+pub fn render_section<'a>(year: u16, reports: impl IntoIterator<Item = &'a PartReport>) -> String {
+    let mut section = format!(
+        "{}\n## {} Benchmarks\n\n| Day | Part | Status | Median |\n| :---: | :---: | :---: | ---: |\n",
+        marker(year),
+        year
+    );
+    let mut total = Duration::ZERO;
+
+    for report in reports {
+        let status = match (report.check(), report.is_failed(), report.is_solved()) {
+            (_, true, _) => "failed",
+            (Check::Pass, _, _) => "✓",
+            (Check::Regression, _, _) => "✗",
+            (_, _, true) => "solved",
+            _ => "not solved",
+        };
+        let time = if report.is_solved() {
+            total += report.duration;
+            format!("{:.2?}", report.duration)
+        } else {
+            "-".into()
+        };
+
+        section.push_str(&format!(
+            "| Day {} | {} | {} | {} |\n",
+            report.day, report.part, status, time
+        ));
+    }
+
+    section.push_str(&format!(
+        "\n**Total: {:.2}ms**\n{}",
+        total.as_secs_f64() * 1000_f64,
+        marker(year)
+    ));
+    section
+}
+
+/// Replaces everything from the first [`marker`] of `year` up to and including the next one with `section`.
+pub fn replace_section(readme: &str, year: u16, section: &str) -> Result<String, String> {
+    let marker = marker(year);
+    let missing = || format!("could not find two \"{}\" markers", marker);
+
+    let start = readme.find(&marker).ok_or_else(missing)?;
+    let end = readme[start + marker.len()..]
+        .find(&marker)
+        .map(|end| start + marker.len() + end + marker.len())
+        .ok_or_else(missing)?;
+
+    Ok(format!("{}{}{}", &readme[..start], section, &readme[end..]))
+}
+
+/// Rewrites the benchmark section of a year in `README.md` in the crate root.
+pub fn update<'a>(year: u16, reports: impl IntoIterator<Item = &'a PartReport>) -> io::Result<()> {
+    let path = crate::crate_root().join(README_FILE);
+    let readme = fs::read_to_string(&path)?;
+    let updated = replace_section(&readme, year, &render_section(year, reports))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if updated != readme {
+        fs::write(path, updated)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_section() {
+        let mut solved = PartReport::new(1, 1, Ok(Some("24000".into())), Duration::from_micros(37));
+        solved.expected = Some("24000".into());
+        let unsolved = PartReport::new(1, 2, Ok(None), Duration::from_micros(1));
+
+        assert_eq!(
+            render_section(2022, [&solved, &unsolved]),
+            format!(
+                "{0}\n## 2022 Benchmarks\n\n| Day | Part | Status | Median |\n| :---: | :---: | :---: | ---: |\n\
+                 | Day 1 | 1 | ✓ | 37.00µs |\n| Day 1 | 2 | not solved | - |\n\n**Total: 0.04ms**\n{0}",
+                marker(2022)
+            )
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let (marker, other) = (marker(2022), super::marker(2021));
+        let readme = format!("# Title\n\n{0}\n{0}\n{1}\n{1}\n## Usage\n", marker, other);
+        let section = format!("{0}\n| table |\n{0}", marker);
+
+        let updated = replace_section(&readme, 2022, &section).unwrap();
+        assert_eq!(
+            updated,
+            format!(
+                "# Title\n\n{0}\n| table |\n{0}\n{1}\n{1}\n## Usage\n",
+                marker, other
+            )
+        );
+        assert_eq!(replace_section(&updated, 2022, &section), Ok(updated));
+        assert!(replace_section("# Title\n", 2022, &section).is_err());
+        assert!(replace_section(&marker, 2022, &section).is_err());
+    }
+}
@@ -0,0 +1,582 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{Display, Write};
+use std::time::{Duration, Instant};
+
+use crate::answers::{self, Check};
+use crate::baseline;
+use crate::bench::BenchStats;
+use crate::memory::{self, AllocStats};
+use crate::style;
+
+/// Name of the environment variable that selects the output format of `solve!`.
+pub const FORMAT_ENV: &str = "AOC_FORMAT";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable output with ANSI styling.
+    Text,
+    /// One JSON object per line and part, see [`PartReport::to_json`].
+    JsonLines,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(Format::Text),
+            "json" | "jsonl" => Some(Format::JsonLines),
+            _ => None,
+        }
+    }
+
+    /// Takes the format from a `--format <text|jsonl>` argument out of `args`, falling back to the
+    /// `AOC_FORMAT` env var. An unknown argument is an error, an unknown env var falls back to text output.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let arg = args.opt_value_from_fn("--format", |value| {
+            Format::parse(value)
+                .ok_or_else(|| format!("invalid format \"{}\", expected text or jsonl", value))
+        })?;
+
+        Ok(arg
+            .or_else(|| Format::parse(&env::var(FORMAT_ENV).ok()?))
+            .unwrap_or(Format::Text))
+    }
+}
+
+/// The outcome of running one part of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the part is not solved yet or failed.
+    pub answer: Option<String>,
+    /// The error message if the part failed.
+    pub error: Option<String>,
+    /// Elapsed time of a single run, or the median if the part was benchmarked.
+    pub duration: Duration,
+    pub bench: Option<BenchStats>,
+    /// The answer recorded in `answers.toml`, if any.
+    pub expected: Option<String>,
+    /// Allocations of a single run, if the `count-allocations` feature is enabled.
+    pub allocs: Option<AllocStats>,
+    /// The duration recorded in `baseline.toml`, if any.
+    pub baseline: Option<Duration>,
+}
+
+impl PartReport {
+    /// Creates a report from the result of [`Answer::into_answer`].
+    pub fn new(
+        day: u8,
+        part: u8,
+        answer: Result<Option<String>, String>,
+        duration: Duration,
+    ) -> Self {
+        let (answer, error) = match answer {
+            Ok(answer) => (answer, None),
+            Err(error) => (None, Some(error)),
+        };
+
+        PartReport {
+            day,
+            part,
+            answer,
+            error,
+            duration,
+            bench: None,
+            expected: None,
+            allocs: None,
+            baseline: None,
+        }
+    }
+
+    /// Prints a failed part's error to stderr.
+    pub fn print_error(&self) {
+        if let Some(error) = &self.error {
+            eprintln!(
+                "{} day {:02}, part {} failed: {}",
+                style::bold("Error:"),
+                self.day,
+                self.part,
+                error
+            );
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Compares the answer against the recorded answer.
+    pub fn check(&self) -> Check {
+        answers::check(self.answer.as_deref(), self.expected.as_deref())
+    }
+
+    /// Change of the duration compared to the baseline in percent, positive if the part got slower.
+    pub fn change(&self) -> Option<f64> {
+        baseline::change(self.duration, self.baseline?)
+    }
+
+    /// Serializes the report as a single-line JSON object, e.g.
+    /// `{"day":1,"part":1,"answer":"24000","duration_ns":37030,"solved":true}`.
+    /// Failed parts additionally contain `error`, parts with a recorded answer contain
+    /// `expected` and `check` (`pass` or `regression`). Benchmarked parts additionally
+    /// contain `iterations`, `min_ns`, `median_ns`, `mean_ns` and `stddev_ns`. Parts with
+    /// counted allocations contain `allocations`, `allocated_bytes` and `peak_bytes`, parts
+    /// with a baseline contain `baseline_ns`.
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Some(answer) => json_string(answer),
+            None => "null".into(),
+        };
+
+        let mut json = format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{},\"solved\":{}",
+            self.day,
+            self.part,
+            answer,
+            self.duration.as_nanos(),
+            self.is_solved()
+        );
+
+        if let Some(error) = &self.error {
+            let _ = write!(json, ",\"error\":{}", json_string(error));
+        }
+
+        if let Some(expected) = &self.expected {
+            let check = match self.check() {
+                Check::Pass => "pass",
+                _ => "regression",
+            };
+            let _ = write!(
+                json,
+                ",\"expected\":{},\"check\":\"{}\"",
+                json_string(expected),
+                check
+            );
+        }
+
+        if let Some(bench) = &self.bench {
+            let _ = write!(
+                json,
+                ",\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}",
+                bench.iterations,
+                bench.min.as_nanos(),
+                bench.median.as_nanos(),
+                bench.mean.as_nanos(),
+                bench.stddev.as_nanos()
+            );
+        }
+
+        if let Some(allocs) = &self.allocs {
+            let _ = write!(
+                json,
+                ",\"allocations\":{},\"allocated_bytes\":{},\"peak_bytes\":{}",
+                allocs.allocations, allocs.bytes, allocs.peak
+            );
+        }
+
+        if let Some(baseline) = &self.baseline {
+            let _ = write!(json, ",\"baseline_ns\":{}", baseline.as_nanos());
+        }
+
+        json.push('}');
+        json
+    }
+
+    /// Parses a line written by [`PartReport::to_json`]. Returns `None` for any other line.
+    pub fn from_json(line: &str) -> Option<Self> {
+        let mut day = None;
+        let mut part = None;
+        let mut answer = None;
+        let mut error = None;
+        let mut expected = None;
+        let mut duration_ns = None;
+        let mut iterations = None;
+        let mut bench_ns = [None; 4];
+        let mut allocs = [None; 3];
+        let mut baseline_ns = None;
+
+        for (key, value) in parse_flat_object(line.trim())? {
+            match (key.as_str(), value) {
+                ("day", JsonValue::Number(n)) => day = u8::try_from(n).ok(),
+                ("part", JsonValue::Number(n)) => part = u8::try_from(n).ok(),
+                ("answer", JsonValue::String(s)) => answer = Some(s),
+                ("error", JsonValue::String(s)) => error = Some(s),
+                ("expected", JsonValue::String(s)) => expected = Some(s),
+                ("duration_ns", JsonValue::Number(n)) => duration_ns = u64::try_from(n).ok(),
+                ("iterations", JsonValue::Number(n)) => iterations = u32::try_from(n).ok(),
+                ("min_ns", JsonValue::Number(n)) => bench_ns[0] = u64::try_from(n).ok(),
+                ("median_ns", JsonValue::Number(n)) => bench_ns[1] = u64::try_from(n).ok(),
+                ("mean_ns", JsonValue::Number(n)) => bench_ns[2] = u64::try_from(n).ok(),
+                ("stddev_ns", JsonValue::Number(n)) => bench_ns[3] = u64::try_from(n).ok(),
+                ("allocations", JsonValue::Number(n)) => allocs[0] = u64::try_from(n).ok(),
+                ("allocated_bytes", JsonValue::Number(n)) => allocs[1] = u64::try_from(n).ok(),
+                ("peak_bytes", JsonValue::Number(n)) => allocs[2] = u64::try_from(n).ok(),
+                ("baseline_ns", JsonValue::Number(n)) => baseline_ns = u64::try_from(n).ok(),
+                _ => {}
+            }
+        }
+
+        let bench = match (iterations, bench_ns) {
+            (Some(iterations), [Some(min), Some(median), Some(mean), Some(stddev)]) => {
+                Some(BenchStats {
+                    iterations,
+                    min: Duration::from_nanos(min),
+                    median: Duration::from_nanos(median),
+                    mean: Duration::from_nanos(mean),
+                    stddev: Duration::from_nanos(stddev),
+                })
+            }
+            _ => None,
+        };
+
+        let allocs = match allocs {
+            [Some(allocations), Some(bytes), Some(peak)] => Some(AllocStats {
+                allocations,
+                bytes,
+                peak,
+            }),
+            _ => None,
+        };
+
+        Some(PartReport {
+            day: day?,
+            part: part?,
+            answer,
+            error,
+            duration: Duration::from_nanos(duration_ns?),
+            bench,
+            expected,
+            allocs,
+            baseline: baseline_ns.map(Duration::from_nanos),
+        })
+    }
+
+    /// Prints the report in the given format. Text output omits the part header,
+    /// see [`print_part_header`].
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::JsonLines => println!("{}", self.to_json()),
+            Format::Text => {
+                let marker = match (self.check(), &self.expected) {
+                    (Check::Pass, _) => " ✓".to_string(),
+                    (Check::Regression, Some(expected)) => format!(" ✗ expected {}", expected),
+                    _ => String::new(),
+                };
+                let baseline = match (self.baseline, self.change()) {
+                    (Some(baseline), Some(change)) => {
+                        format!(", baseline: {:.2?}, {:+.2}%", baseline, change)
+                    }
+                    _ => String::new(),
+                };
+                let allocs = self.allocs.map_or_else(String::new, |allocs| {
+                    format!(
+                        ", allocations: {}, allocated: {}, peak: {}",
+                        allocs.allocations,
+                        memory::format_bytes(allocs.bytes),
+                        memory::format_bytes(allocs.peak)
+                    )
+                });
+
+                match (&self.answer, &self.bench) {
+                    _ if self.is_failed() => println!("failed.{}", marker),
+                    (Some(answer), Some(bench)) => println!(
+                        "{}{} {}",
+                        answer,
+                        marker,
+                        style::italic(format!(
+                            "(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, iterations: {}{}{})",
+                            bench.median,
+                            bench.mean,
+                            bench.stddev,
+                            bench.min,
+                            bench.iterations,
+                            baseline,
+                            allocs
+                        ))
+                    ),
+                    (Some(answer), None) => println!(
+                        "{}{} {}",
+                        answer,
+                        marker,
+                        style::italic(format!(
+                            "(elapsed: {:.2?}{}{})",
+                            self.duration, baseline, allocs
+                        ))
+                    ),
+                    (None, _) => println!("not solved.{}", marker),
+                }
+            }
+        }
+    }
+}
+
+/// Calls `func` and measures its execution time.
+pub fn time<T>(func: impl FnOnce() -> T) -> (T, Duration) {
+    let timer = Instant::now();
+    let result = func();
+    (result, timer.elapsed())
+}
+
+/// Return types accepted from solvers: `Option<T>` for parts that may be unsolved
+/// and `Result<T, E>` for parts that can fail, e.g. on malformed input.
+pub trait Answer {
+    /// `Ok(None)` if the part is not solved, `Err` with the error message if it failed.
+    fn into_answer(self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> Answer for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+impl<T: Display, E: Display> Answer for Result<T, E> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        self.map(|answer| Some(answer.to_string()))
+            .map_err(|error| error.to_string())
+    }
+}
+
+/// Runs a solver and measures its execution time and allocations.
+/// Formatting the answer is not measured.
+pub fn measure_part<T: Answer>(day: u8, part: u8, func: impl FnOnce() -> T) -> PartReport {
+    let ((result, duration), allocs) = memory::measure(|| time(func));
+    let mut report = PartReport::new(day, part, result.into_answer(), duration);
+    report.allocs = allocs;
+    report
+}
+
+/// Prints the time spent parsing a day's input, in `jsonl` as [`parse_time_to_json`].
+pub fn print_parse(day: u8, duration: Duration, format: Format) {
+    match format {
+        Format::JsonLines => println!("{}", parse_time_to_json(day, duration)),
+        Format::Text => println!(
+            "🎄 {} 🎄 {}",
+            style::bold("Parse"),
+            style::italic(format!("(elapsed: {:.2?})", duration))
+        ),
+    }
+}
+
+/// Serializes the parse time of a day as a single-line JSON object, e.g. `{"day":1,"parse_ns":41710}`.
+pub fn parse_time_to_json(day: u8, duration: Duration) -> String {
+    format!("{{\"day\":{},\"parse_ns\":{}}}", day, duration.as_nanos())
+}
+
+/// Parses a line written by [`parse_time_to_json`]. Returns `None` for any other line.
+pub fn parse_time_from_json(line: &str) -> Option<Duration> {
+    parse_flat_object(line.trim())?
+        .into_iter()
+        .find_map(|(key, value)| match (key.as_str(), value) {
+            ("parse_ns", JsonValue::Number(n)) => u64::try_from(n).ok().map(Duration::from_nanos),
+            _ => None,
+        })
+}
+
+pub fn print_part_header(part: u8) {
+    println!("🎄 {} 🎄", style::bold(format!("Part {}", part)));
+}
+
+pub(crate) fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Parses a string in double quotes with the escapes written by [`json_string`].
+/// TOML basic strings use the same escapes.
+fn parse_string(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<String> {
+    if chars.next()? != '"' {
+        return None;
+    }
+    let mut out = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'u' => {
+                    let code: String = (0..4).filter_map(|_| chars.next()).collect();
+                    out.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                c => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+/// Unescapes a complete quoted string written by [`json_string`], e.g. a value of `answers.toml`.
+pub(crate) fn unquote(value: &str) -> Option<String> {
+    let mut chars = value.chars().peekable();
+    let unquoted = parse_string(&mut chars)?;
+    chars.next().is_none().then_some(unquoted)
+}
+
+#[derive(Debug, PartialEq)]
+enum JsonValue {
+    String(String),
+    Number(u128),
+    Bool(bool),
+    Null,
+}
+
+/// Minimal parser for the flat JSON objects this module writes.
+/// Nested values and fractional numbers are not supported.
+fn parse_flat_object(input: &str) -> Option<Vec<(String, JsonValue)>> {
+    let mut chars = input.chars().peekable();
+    let mut fields = vec![];
+
+    fn skip_ws(chars: &mut std::iter::Peekable<std::str::Chars>) {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    skip_ws(&mut chars);
+    if chars.next()? != '{' {
+        return None;
+    }
+
+    loop {
+        skip_ws(&mut chars);
+        if chars.next_if_eq(&'}').is_some() {
+            break;
+        }
+
+        let key = parse_string(&mut chars)?;
+        skip_ws(&mut chars);
+        if chars.next()? != ':' {
+            return None;
+        }
+        skip_ws(&mut chars);
+
+        let value = match chars.peek()? {
+            '"' => JsonValue::String(parse_string(&mut chars)?),
+            c if c.is_ascii_digit() => {
+                let mut digits = String::new();
+                while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                    digits.push(c);
+                }
+                JsonValue::Number(digits.parse().ok()?)
+            }
+            _ => {
+                let word: String =
+                    std::iter::from_fn(|| chars.next_if(|c| c.is_alphabetic())).collect();
+                match word.as_str() {
+                    "true" => JsonValue::Bool(true),
+                    "false" => JsonValue::Bool(false),
+                    "null" => JsonValue::Null,
+                    _ => return None,
+                }
+            }
+        };
+        fields.push((key, value));
+
+        skip_ws(&mut chars);
+        match chars.next()? {
+            ',' => continue,
+            '}' => break,
+            _ => return None,
+        }
+    }
+
+    skip_ws(&mut chars);
+    chars.next().is_none().then_some(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_roundtrip() {
+        let solved = PartReport {
+            day: 5,
+            part: 1,
+            answer: Some("C\"M\\Z\n".into()),
+            error: None,
+            duration: Duration::from_nanos(74),
+            expected: Some("CMZ".into()),
+            bench: Some(BenchStats {
+                iterations: 3,
+                min: Duration::from_nanos(70),
+                median: Duration::from_nanos(74),
+                mean: Duration::from_nanos(75),
+                stddev: Duration::from_nanos(4),
+            }),
+            allocs: Some(AllocStats {
+                allocations: 2,
+                bytes: 1500,
+                peak: 1000,
+            }),
+            baseline: Some(Duration::from_nanos(80)),
+        };
+        let unsolved = PartReport {
+            day: 25,
+            part: 2,
+            answer: None,
+            error: None,
+            duration: Duration::from_secs(10),
+            bench: None,
+            expected: None,
+            allocs: None,
+            baseline: None,
+        };
+
+        assert_eq!(
+            unsolved.to_json(),
+            "{\"day\":25,\"part\":2,\"answer\":null,\"duration_ns\":10000000000,\"solved\":false}"
+        );
+        assert_eq!(PartReport::from_json(&solved.to_json()), Some(solved));
+        assert_eq!(PartReport::from_json(&unsolved.to_json()), Some(unsolved));
+
+        let failed = PartReport::new(2, 1, Err("unexpected 'Q'".into()), Duration::ZERO);
+        assert!(failed.to_json().contains(",\"error\":\"unexpected 'Q'\""));
+        assert_eq!(PartReport::from_json(&failed.to_json()), Some(failed));
+    }
+
+    #[test]
+    fn test_parse_time_json_roundtrip() {
+        let json = parse_time_to_json(7, Duration::from_nanos(41710));
+        assert_eq!(json, "{\"day\":7,\"parse_ns\":41710}");
+        assert_eq!(
+            parse_time_from_json(&json),
+            Some(Duration::from_nanos(41710))
+        );
+        assert_eq!(PartReport::from_json(&json), None);
+        assert_eq!(
+            parse_time_from_json("{\"day\":7,\"part\":1,\"duration_ns\":5}"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_json_rejects_other_lines() {
+        assert_eq!(PartReport::from_json("🎄 Part 1 🎄"), None);
+        assert_eq!(PartReport::from_json("{\"day\":1}"), None);
+        assert_eq!(PartReport::from_json("{\"day\":1,}x"), None);
+    }
+}
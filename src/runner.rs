@@ -0,0 +1,857 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::any::Any;
+use std::io;
+use std::process::{self, Command, Output, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::answers::{Answers, ANSWERS_FILE};
+use crate::baseline::{Baseline, BASELINE_FILE};
+use crate::bench::{self, BenchOptions, ProfileOptions};
+use crate::days::{self, Day};
+use crate::input;
+use crate::limits::{self, MEMORY_LIMIT_ENV};
+use crate::log;
+use crate::memory;
+use crate::report::{self, print_parse, print_part_header, Format, PartReport, FORMAT_ENV};
+use crate::solution::{self, Solution};
+use crate::style;
+use crate::visualize;
+
+/// Options of a day binary.
+struct DayArgs {
+    /// Only run this part.
+    part: Option<u8>,
+    /// Store the answers of all solved parts as the new expected answers.
+    record: bool,
+    /// The file passed to `--input`, see [`crate::input_override`].
+    input: Option<String>,
+    format: Format,
+    /// Memory limit in megabytes, see [`limits::memory_limit_from_args`].
+    memory_limit: Option<u64>,
+    /// Only loop one part, see [`ProfileOptions::from_args`].
+    profile: Option<ProfileOptions>,
+    /// Benchmark every part, see [`BenchOptions::from_args`].
+    bench: Option<BenchOptions>,
+    /// Where frames are drawn, see [`visualize::Target::from_args`].
+    visualize: Option<visualize::Target>,
+}
+
+fn parse_day_args() -> Result<DayArgs, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let day_args = DayArgs {
+        part: args.opt_value_from_fn("--part", parse_part)?,
+        record: args.contains("--record"),
+        input: args.opt_value_from_str(crate::INPUT_ARG)?,
+        format: Format::from_args(&mut args)?,
+        memory_limit: limits::memory_limit_from_args(&mut args)?,
+        profile: ProfileOptions::from_args(&mut args)?,
+        bench: BenchOptions::from_args(&mut args)?,
+        visualize: visualize::Target::from_args(&mut args)?,
+    };
+    style::remove_color_arg(&mut args)?;
+    log::remove_verbosity_args(&mut args);
+    reject_unknown_args(args)?;
+    Ok(day_args)
+}
+
+/// Fails on the first argument that was not taken out of `args` by one of the parsers.
+pub fn reject_unknown_args(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    match args.finish().first() {
+        Some(arg) => Err(pico_args::Error::Utf8ArgumentParsingFailed {
+            value: arg.to_string_lossy().into_owned(),
+            cause: "unknown argument".into(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Runs the parts of a day binary and prints their results and the parse time. This is what
+/// `solve!` expands to. Exits the process with a non-zero status once all selected parts ran
+/// if any of them failed, or after the finished parts if one panicked.
+/// With `--profile`, only loops the profiled part.
+pub fn run_day<S: Solution>(year: u16, day: u8, input: &str) {
+    let args = match parse_day_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    // frames and their delay would be drawn inside the timed parts.
+    if args.visualize.is_some() && (args.bench.is_some() || args.profile.is_some()) {
+        eprintln!("`--visualize` and `--visualize-dir` can not be combined with `--bench` or `--profile`.");
+        process::exit(1);
+    }
+    limits::apply_memory_limit(args.memory_limit);
+    if let Some(profile) = args.profile {
+        profile_part::<S>(day, profile, input);
+        return;
+    }
+
+    // recorded answers belong to the real input, other inputs are not checked against them.
+    let custom_input = args.input.is_some();
+    let mut answers = if custom_input {
+        Answers::default()
+    } else {
+        load_answers(year, args.record)
+    };
+
+    let format = args.format;
+    let mut run = solution::run::<S>(day, input, args.part, args.bench);
+
+    print_parse(day, run.parse, format);
+    for report in &mut run.parts {
+        report.expected = answers.get(day, report.part).map(String::from);
+        if format == Format::Text {
+            print_part_header(report.part);
+        }
+        report.print(format);
+        report.print_error();
+    }
+
+    if args.record {
+        if custom_input {
+            eprintln!("Not recording answers for an input passed with --input.");
+        } else {
+            record_answers(&mut answers, year, &run.parts);
+        }
+    }
+
+    // the panic hook already printed the message, exit like an uncaught panic would.
+    if run.panic.is_some() {
+        process::exit(101);
+    }
+    if run.parts.iter().any(PartReport::is_failed) {
+        process::exit(1);
+    }
+}
+
+/// Parses the input once, loops a part for `--profile` and prints a single line with the total time.
+fn profile_part<S: Solution>(day: u8, options: ProfileOptions, input: &str) {
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!(
+                "{} could not parse input of day {:02}: {}",
+                style::bold("Error:"),
+                day,
+                error
+            );
+            process::exit(1);
+        }
+    };
+    let elapsed = match options.part {
+        1 => bench::profile(options, S::part_one, &parsed),
+        _ => bench::profile(options, S::part_two, &parsed),
+    };
+    println!(
+        "🎄 {} 🎄 {}",
+        style::bold(format!("Part {}", options.part)),
+        style::italic(format!(
+            "(profiled {} iterations in {:.2?}, {:.2?} per iteration)",
+            options.iterations,
+            elapsed,
+            elapsed / options.iterations.max(1)
+        ))
+    );
+}
+
+/// Parses a list of days and day ranges, e.g. `3-7,9`, into sorted unique day numbers.
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "invalid day \"{}\", expected a number from 1 to 25",
+            day
+        )),
+    };
+
+    let mut days = vec![];
+    for item in spec.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("invalid day range \"{}\"", item));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+/// Parses a part number, which must be `1` or `2`.
+pub fn parse_part(part: &str) -> Result<u8, String> {
+    match part.trim() {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part \"{}\", expected 1 or 2", part)),
+    }
+}
+
+/// Parses a timeout in seconds, e.g. `10` or `0.5`.
+pub fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    seconds
+        .trim()
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| {
+            format!(
+                "invalid timeout \"{}\", expected a number of seconds",
+                seconds
+            )
+        })
+}
+
+/// Parses a year, e.g. `2022`.
+pub fn parse_year(year: &str) -> Result<u16, String> {
+    match year.trim().parse::<u16>() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => Err(format!("invalid year \"{}\", expected 2015 or later", year)),
+    }
+}
+
+/// Whether a day has a solution, either in the registry or as a binary in `src/bin`.
+pub fn is_implemented(year: u16, day: u8) -> bool {
+    days::get(year, day).is_some()
+        || crate::crate_root()
+            .join("src/bin")
+            .join(format!("{}.rs", crate::bin_name(year, day)))
+            .exists()
+}
+
+/// Loads the `answers.toml` of a year, printing a warning and falling back to no answers if it is invalid.
+/// With `record`, exits the process instead, since recording would overwrite the answers in the file.
+pub fn load_answers(year: u16, record: bool) -> Answers {
+    Answers::load(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read {} of {}: {}", ANSWERS_FILE, year, e);
+        if record {
+            eprintln!("Not recording answers over it, fix or remove the file first.");
+            process::exit(1);
+        }
+        Answers::default()
+    })
+}
+
+/// Stores the answers of all solved parts in the `answers.toml` of a year.
+pub fn record_answers<'a>(
+    answers: &mut Answers,
+    year: u16,
+    reports: impl IntoIterator<Item = &'a PartReport>,
+) {
+    let mut recorded = 0;
+    for report in reports {
+        if let Some(answer) = &report.answer {
+            answers.set(report.day, report.part, answer);
+            recorded += 1;
+        }
+    }
+
+    match answers.save(year) {
+        Ok(_) => eprintln!(
+            "Recorded {} answer(s) in {} of {}.",
+            recorded, ANSWERS_FILE, year
+        ),
+        Err(e) => eprintln!("Failed to record answers: {}", e),
+    }
+}
+
+/// Loads the `baseline.toml` of a year, printing a warning and falling back to no baseline if it is invalid.
+/// With `save`, exits the process instead, since saving would overwrite the timings in the file.
+pub fn load_baseline(year: u16, save: bool) -> Baseline {
+    Baseline::load(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read {} of {}: {}", BASELINE_FILE, year, e);
+        if save {
+            eprintln!("Not saving a baseline over it, fix or remove the file first.");
+            process::exit(1);
+        }
+        Baseline::default()
+    })
+}
+
+/// Stores the timings of all solved parts in the `baseline.toml` of a year.
+pub fn save_baseline<'a>(
+    baseline: &mut Baseline,
+    year: u16,
+    reports: impl IntoIterator<Item = &'a PartReport>,
+) {
+    let mut saved = 0;
+    for report in reports.into_iter().filter(|report| report.is_solved()) {
+        baseline.set(report.day, report.part, report.duration);
+        saved += 1;
+    }
+
+    match baseline.save(year) {
+        Ok(_) => eprintln!(
+            "Saved {} timing(s) in {} of {}.",
+            saved, BASELINE_FILE, year
+        ),
+        Err(e) => eprintln!("Failed to save baseline: {}", e),
+    }
+}
+
+/// How to run a day in [`run_in_process`] and [`run_in_subprocess`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Only run this part. Runs both parts if `None`.
+    pub part: Option<u8>,
+    /// Give up on a day that takes longer than this.
+    pub timeout: Option<Duration>,
+    /// Memory limit in megabytes for day binaries, see [`limits::set_memory_limit`].
+    /// In-process runs share the limit of the runner process instead.
+    pub memory_limit: Option<u64>,
+    /// Benchmark every part and report its median instead of a single run.
+    pub bench: Option<BenchOptions>,
+}
+
+/// The outcome of running all parts of one day.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub status: DayStatus,
+    /// Time spent parsing the input, if the day got that far.
+    pub parse: Option<Duration>,
+    pub reports: Vec<PartReport>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    /// The day ran and `reports` holds the results of its parts.
+    Ran,
+    /// There is no solution for this day.
+    NotSolved,
+    /// The solution exists but its input file is missing or empty, see [`InputError`](crate::input::InputError).
+    MissingInput(String),
+    /// The day binary printed output that does not contain any structured results.
+    Unstructured(String),
+    /// The day did not finish within the timeout.
+    TimedOut(Duration),
+    /// The day binary did not compile, with the first compiler error.
+    BuildFailed(String),
+    /// The day panicked, with the panic message. `reports` holds the parts that finished before.
+    Panicked(String),
+    /// The day binary exited unsuccessfully without a panic, e.g. because it ran out of memory.
+    Crashed(String),
+}
+
+impl DayRun {
+    fn new(day: u8, status: DayStatus) -> Self {
+        DayRun {
+            day,
+            status,
+            parse: None,
+            reports: vec![],
+        }
+    }
+
+    /// Time spent in the solutions of this day, summed from the exact durations of its parse and parts.
+    /// Falls back to [`crate::parse_exec_time`] for binaries that do not print structured results.
+    pub fn duration(&self) -> Duration {
+        match &self.status {
+            DayStatus::Unstructured(output) => {
+                Duration::from_secs_f64(crate::parse_exec_time(output) / 1000_f64)
+            }
+            _ => {
+                self.parse.unwrap_or_default()
+                    + self
+                        .reports
+                        .iter()
+                        .map(|report| report.duration)
+                        .sum::<Duration>()
+            }
+        }
+    }
+}
+
+/// Runs a day by calling its registered solution in this process.
+/// If the day times out, its thread is abandoned and keeps running in the background.
+pub fn run_in_process(year: u16, day: u8, options: RunOptions) -> DayRun {
+    let Some(timeout) = options.timeout else {
+        return run_registered(year, day, options);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name(crate::bin_name(year, day))
+        .spawn(move || {
+            let _ = sender.send(run_registered(year, day, options));
+        })
+        .unwrap();
+
+    match receiver.recv_timeout(timeout) {
+        Ok(run) => run,
+        Err(mpsc::RecvTimeoutError::Timeout) => DayRun::new(day, DayStatus::TimedOut(timeout)),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            unreachable!("day {} finished without a result", day)
+        }
+    }
+}
+
+/// Runs a registered solution, turning a panic into [`DayStatus::Panicked`].
+fn run_registered(year: u16, day: u8, options: RunOptions) -> DayRun {
+    let Some(solution) = days::get(year, day) else {
+        return DayRun::new(day, DayStatus::NotSolved);
+    };
+
+    run_solution(solution, options)
+}
+
+/// The message passed to `panic!`, which is either a `&str` or a `String`.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "unknown panic".into(),
+        },
+    }
+}
+
+fn run_solution(solution: &Day, options: RunOptions) -> DayRun {
+    let input = match input::load("inputs", solution.year, solution.day) {
+        Ok(input) => input,
+        Err(e) => return DayRun::new(solution.day, DayStatus::MissingInput(e.to_string())),
+    };
+
+    let run = solution.run(&input, options.part, options.bench);
+
+    DayRun {
+        day: solution.day,
+        status: run.panic.map_or(DayStatus::Ran, DayStatus::Panicked),
+        parse: Some(run.parse),
+        reports: run.parts,
+    }
+}
+
+/// Runs a day by spawning its binary with `cargo run --release`.
+/// If the day times out, the binary is killed. Days without a solution are not spawned.
+pub fn run_in_subprocess(year: u16, day: u8, options: RunOptions) -> DayRun {
+    // cargo would only fail to find the binary, and every attempt waits for the build lock.
+    if !is_implemented(year, day) {
+        return DayRun::new(day, DayStatus::NotSolved);
+    }
+
+    let mut args = vec![
+        "run".into(),
+        "--release".into(),
+        "--bin".into(),
+        crate::bin_name(year, day),
+    ];
+    if memory::ENABLED {
+        args.extend(["--features".into(), "count-allocations".into()]);
+    }
+
+    let mut day_args = vec![];
+    if let Some(part) = options.part {
+        day_args.extend(["--part".into(), part.to_string()]);
+    }
+    if let Some(bench) = options.bench {
+        day_args.extend(bench.to_args());
+    }
+    if !day_args.is_empty() {
+        args.push("--".into());
+        args.extend(day_args);
+    }
+
+    let mut command = Command::new("cargo");
+    command
+        .args(args)
+        .current_dir(crate::crate_root())
+        .env(FORMAT_ENV, "jsonl")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(megabytes) = options.memory_limit {
+        command.env(MEMORY_LIMIT_ENV, megabytes.to_string());
+    }
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut command, 0);
+
+    let child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            return DayRun::new(
+                day,
+                DayStatus::Crashed(format!("failed to start cargo: {}", e)),
+            )
+        }
+    };
+
+    let cmd = match limits::wait_with_timeout(child, options.timeout) {
+        Ok(Some(cmd)) => cmd,
+        Ok(None) => {
+            return DayRun::new(
+                day,
+                DayStatus::TimedOut(options.timeout.unwrap_or_default()),
+            )
+        }
+        Err(e) => {
+            return DayRun::new(
+                day,
+                DayStatus::Crashed(format!("failed to wait for cargo: {}", e)),
+            )
+        }
+    };
+
+    parse_output(year, day, cmd)
+}
+
+/// Classifies the output of `cargo run --bin <year>-<day>` with `jsonl` output into a [`DayRun`].
+pub fn parse_output(year: u16, day: u8, cmd: Output) -> DayRun {
+    let output = String::from_utf8_lossy(&cmd.stdout).into_owned();
+    let reports: Vec<PartReport> = output.lines().filter_map(PartReport::from_json).collect();
+    let parse = output.lines().find_map(report::parse_time_from_json);
+    let stderr = String::from_utf8_lossy(&cmd.stderr);
+    let day_stderr = day_stderr(&stderr);
+    let input_error = day_stderr
+        .lines()
+        .find_map(|line| line.strip_prefix(input::LOAD_ERROR_PREFIX));
+    // parts that fail exit unsuccessfully on purpose, after printing their report.
+    let crashed = !cmd.status.success() && !reports.iter().any(|report| report.is_failed());
+
+    let status = if !is_implemented(year, day) {
+        DayStatus::NotSolved
+    } else if let Some(error) = parse_build_error(&stderr) {
+        DayStatus::BuildFailed(error)
+    } else if let Some(error) = input_error {
+        DayStatus::MissingInput(error.into())
+    } else if let Some(message) = parse_panic(day_stderr) {
+        DayStatus::Panicked(message)
+    } else if crashed {
+        match day_stderr.lines().find(|line| !line.trim().is_empty()) {
+            Some(line) => DayStatus::Crashed(format!("{}: {}", cmd.status, line.trim())),
+            None => DayStatus::Crashed(cmd.status.to_string()),
+        }
+    } else if !reports.is_empty() {
+        DayStatus::Ran
+    } else if !output.is_empty() {
+        DayStatus::Unstructured(output)
+    } else {
+        DayStatus::NotSolved
+    };
+
+    DayRun {
+        day,
+        status,
+        parse,
+        reports,
+    }
+}
+
+/// The part of the stderr of `cargo run` that was written by the day binary, after cargo's `Running` line.
+fn day_stderr(stderr: &str) -> &str {
+    match stderr.find("Running `") {
+        Some(start) => stderr[start..]
+            .split_once('\n')
+            .map_or("", |(_, rest)| rest),
+        None => stderr,
+    }
+}
+
+/// The first compiler error in the stderr of `cargo run` or `cargo test`, if the binary did not compile.
+pub fn parse_build_error(stderr: &str) -> Option<String> {
+    if !stderr.contains("error: could not compile") {
+        return None;
+    }
+
+    let mut lines = stderr.lines();
+    let error = lines.find(|line| line.starts_with("error"))?;
+    match lines
+        .next()
+        .and_then(|line| line.trim().strip_prefix("--> "))
+    {
+        Some(location) => Some(format!("{} ({})", error, location)),
+        None => Some(error.into()),
+    }
+}
+
+/// The message and location of a panic in the stderr of a day binary, e.g.
+///
+/// ```text
+/// thread 'main' panicked at src/bin/2022-06.rs:4:39:
+/// index out of bounds: the len is 0 but the index is 3
+/// ```
+pub fn parse_panic(stderr: &str) -> Option<String> {
+    let mut lines = stderr.lines();
+    let header =
+        lines.find(|line| line.starts_with("thread '") && line.contains(" panicked at "))?;
+    let (_, location) = header.split_once(" panicked at ")?;
+
+    match location.strip_suffix(':') {
+        Some(location) => {
+            let message: Vec<&str> = lines
+                .take_while(|line| {
+                    !line.is_empty() && !line.starts_with("note: ") && *line != "stack backtrace:"
+                })
+                .collect();
+            Some(format!("{} ({})", message.join("\n"), location))
+        }
+        // before Rust 1.73, the message was part of the header: `panicked at 'message', location`.
+        None => Some(location.into()),
+    }
+}
+
+/// The outcome of `cargo test` for the unit tests of one day binary.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TestRun {
+    /// The tests ran. `failures` holds the names and panic messages of the failed tests.
+    Finished {
+        passed: u32,
+        failed: u32,
+        failures: Vec<(String, Option<String>)>,
+    },
+    /// The tests did not compile, with the first compiler error.
+    BuildFailed(String),
+    /// `cargo test` did not print a result, e.g. because the binary does not exist.
+    NoResults,
+}
+
+/// Runs the unit tests of a day binary with `cargo test` in the dev profile.
+pub fn run_tests(year: u16, day: u8) -> io::Result<TestRun> {
+    let output = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &crate::bin_name(year, day)])
+        .current_dir(crate::crate_root())
+        .stdin(Stdio::null())
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if let Some(error) = parse_build_error(&stderr) {
+        return Ok(TestRun::BuildFailed(error));
+    }
+
+    Ok(match parse_test_result(&stdout) {
+        Some((passed, failed)) => TestRun::Finished {
+            passed,
+            failed,
+            failures: parse_test_failures(&stdout),
+        },
+        None => TestRun::NoResults,
+    })
+}
+
+/// Passed and failed tests from the `test result:` line of `cargo test`.
+fn parse_test_result(stdout: &str) -> Option<(u32, u32)> {
+    let line = stdout
+        .lines()
+        .find(|line| line.starts_with("test result:"))?;
+    let count = |label: &str| {
+        line.split(';')
+            .find_map(|item| item.trim().strip_suffix(label))
+            .and_then(|item| item.rsplit(' ').next()?.parse().ok())
+    };
+    Some((count(" passed")?, count(" failed")?))
+}
+
+/// Names and panic messages of failed tests, from their `---- <name> stdout ----` sections.
+fn parse_test_failures(stdout: &str) -> Vec<(String, Option<String>)> {
+    stdout
+        .split("\n---- ")
+        .skip(1)
+        .filter_map(|section| {
+            let (header, output) = section.split_once('\n')?;
+            let name = header.strip_suffix(" stdout ----")?;
+            Some((name.to_string(), parse_panic(output)))
+        })
+        .collect()
+}
+
+/// Runs the given days on up to `jobs` worker threads and returns their results in day order.
+pub fn run_parallel(days: &[u8], jobs: usize, run: impl Fn(u8) -> DayRun + Sync) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(days.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = run(day);
+                    runs.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| run.day);
+    runs
+}
+
+/// Builds all day binaries up front, so that subprocess timeouts do not include compile time.
+pub fn build_release() {
+    let mut args = vec!["build", "--release", "--bins"];
+    if memory::ENABLED {
+        args.extend(["--features", "count-allocations"]);
+    }
+
+    let status = Command::new("cargo")
+        .args(args)
+        .current_dir(crate::crate_root())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    // failed days are reported individually when they are run.
+    if !status.is_ok_and(|status| status.success()) {
+        eprintln!("Failed to build all binaries up front.");
+    }
+}
+
+/// Number of worker threads to use when running days in parallel.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3-7,9"), Ok(vec![3, 4, 5, 6, 7, 9]));
+        assert_eq!(parse_days("9, 1,1-2"), Ok(vec![1, 2, 9]));
+        assert_eq!(parse_days("25"), Ok(vec![25]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("7-3").is_err());
+        assert!(parse_days("1,,2").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse_part("2"), Ok(2));
+        assert!(parse_part("3").is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(parse_year("2022"), Ok(2022));
+        assert!(parse_year("22").is_err());
+    }
+
+    #[test]
+    fn test_parse_panic() {
+        let stderr = "    Finished `release` profile [optimized] target(s) in 0.02s\n     \
+                      Running `target/release/2022-06`\n\n\
+                      thread 'main' (11118) panicked at src/bin/2022-06.rs:4:39:\n\
+                      index out of bounds: the len is 0 but the index is 3\n\
+                      note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+        assert_eq!(
+            parse_panic(day_stderr(stderr)),
+            Some(
+                "index out of bounds: the len is 0 but the index is 3 (src/bin/2022-06.rs:4:39)"
+                    .into()
+            )
+        );
+        assert_eq!(parse_panic("Failed to load input: ..."), None);
+    }
+
+    #[test]
+    fn test_parse_build_error() {
+        let stderr = "   Compiling advent_of_code v0.8.0 (/root/crate)\n\
+                      error[E0425]: cannot find value `x` in this scope\n \
+                      --> src/bin/2022-06.rs:4:13\n\
+                      error: could not compile `advent_of_code` (bin \"2022-06\") due to 1 previous error\n";
+        assert_eq!(
+            parse_build_error(stderr),
+            Some(
+                "error[E0425]: cannot find value `x` in this scope (src/bin/2022-06.rs:4:13)"
+                    .into()
+            )
+        );
+        assert_eq!(
+            parse_build_error("     Running `target/release/2022-06`\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_test_result() {
+        let stdout = "running 2 tests\n.F\nfailures:\n\n\
+                      ---- tests::test_part_two stdout ----\n\n\
+                      thread 'tests::test_part_two' panicked at src/bin/2022-01.rs:20:9:\n\
+                      assertion `left == right` failed\n\n\
+                      failures:\n    tests::test_part_two\n\n\
+                      test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out\n";
+
+        assert_eq!(parse_test_result(stdout), Some((1, 1)));
+        assert_eq!(
+            parse_test_failures(stdout),
+            vec![(
+                "tests::test_part_two".into(),
+                Some("assertion `left == right` failed (src/bin/2022-01.rs:20:9)".into())
+            )]
+        );
+        assert_eq!(parse_test_result("error: could not compile"), None);
+    }
+
+    #[test]
+    fn test_panic_message() {
+        assert_eq!(panic_message(&"static"), "static");
+        assert_eq!(panic_message(&String::from("owned")), "owned");
+        assert_eq!(panic_message(&1), "unknown panic");
+    }
+
+    #[test]
+    fn test_day_run_duration() {
+        let ns = Duration::from_nanos;
+        let run = DayRun {
+            day: 1,
+            status: DayStatus::Ran,
+            parse: Some(ns(41)),
+            reports: vec![
+                PartReport::new(1, 1, Ok(Some("24000".into())), ns(170)),
+                PartReport::new(1, 2, Ok(None), ns(30)),
+            ],
+        };
+        assert_eq!(run.duration(), ns(241));
+
+        let run = DayRun::new(1, DayStatus::Unstructured("0 (elapsed: 1.50ms)".into()));
+        assert_eq!(run.duration(), Duration::from_micros(1500));
+    }
+
+    #[test]
+    fn test_reject_unknown_args() {
+        let args =
+            |args: &[&str]| pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+        assert!(reject_unknown_args(args(&[])).is_ok());
+
+        let mut known = args(&["--parts", "2", "--record"]);
+        assert!(known.contains("--record"));
+        let error = reject_unknown_args(known).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "failed to parse '--parts': unknown argument"
+        );
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("10"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_timeout("0.5"), Ok(Duration::from_millis(500)));
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+
+    #[test]
+    fn test_run_parallel_keeps_day_order() {
+        let days: Vec<u8> = (1..=25).rev().collect();
+        let runs = run_parallel(&days, 4, |day| DayRun::new(day, DayStatus::NotSolved));
+        assert_eq!(
+            runs.iter().map(|run| run.day).collect::<Vec<_>>(),
+            (1..=25).collect::<Vec<_>>()
+        );
+    }
+}
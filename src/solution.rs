@@ -0,0 +1,187 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use crate::bench::{self, BenchOptions};
+use crate::report::{measure_part, time, Answer, PartReport};
+use crate::runner;
+
+/// A day's solution with parsing split from solving, so both parts share one parsed input
+/// and parse time can be measured separately from part time.
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Parsed;
+    /// Why an input could not be parsed. Days that accept any input use [`std::convert::Infallible`].
+    type Error: Display;
+    /// Return type of part one, usually `Option<T>` or `Result<T, E>`, see [`Answer`].
+    type PartOne: Answer;
+    type PartTwo: Answer;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error>;
+
+    fn part_one(parsed: &Self::Parsed) -> Self::PartOne;
+
+    fn part_two(parsed: &Self::Parsed) -> Self::PartTwo;
+}
+
+/// Answers, timings and allocations of running both parts of a [`Solution`] once.
+#[derive(Debug)]
+pub struct SolutionRun {
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+    /// Message of a panic while parsing or solving. `parts` holds the parts that finished before.
+    pub panic: Option<String>,
+}
+
+/// Parses the input once and runs the selected part, or both parts if `part` is `None`.
+/// Parts are benchmarked against the parsed input if `bench` is set.
+/// If the input can not be parsed, the selected parts fail with the parse error.
+/// A panic stops the run, see [`SolutionRun::panic`].
+pub fn run<S: Solution>(
+    day: u8,
+    input: &str,
+    part: Option<u8>,
+    bench: Option<BenchOptions>,
+) -> SolutionRun {
+    let mut run = SolutionRun {
+        parse: Duration::ZERO,
+        parts: vec![],
+        panic: None,
+    };
+
+    let parsed = match catch_panic(|| time(|| S::parse(input))) {
+        Ok((parsed, parse)) => {
+            run.parse = parse;
+            parsed
+        }
+        Err(message) => {
+            run.panic = Some(message);
+            return run;
+        }
+    };
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            let error = format!("could not parse input: {}", error);
+            for part in selected_parts(part) {
+                run.parts.push(PartReport::new(
+                    day,
+                    part,
+                    Err(error.clone()),
+                    Duration::ZERO,
+                ));
+            }
+            return run;
+        }
+    };
+
+    for part in selected_parts(part) {
+        let report = catch_panic(|| match part {
+            1 => run_part(day, 1, S::part_one, &parsed, bench),
+            _ => run_part(day, 2, S::part_two, &parsed, bench),
+        });
+        match report {
+            Ok(report) => run.parts.push(report),
+            Err(message) => {
+                run.panic = Some(message);
+                break;
+            }
+        }
+    }
+
+    run
+}
+
+/// The selected part, or both parts if `part` is `None`.
+fn selected_parts(part: Option<u8>) -> impl Iterator<Item = u8> {
+    [1, 2]
+        .into_iter()
+        .filter(move |&p| part.is_none_or(|part| part == p))
+}
+
+/// Calls `func` and returns the message of its panic, if it panics.
+/// The panic hook still prints the panic as usual.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(func))
+        .map_err(|payload| runner::panic_message(payload.as_ref()))
+}
+
+/// Runs a part once and, if `bench` is set and the part did not fail, benchmarks it.
+/// The duration of a benchmarked part is its median.
+pub fn run_part<I: ?Sized, T: Answer>(
+    day: u8,
+    part: u8,
+    solver: impl Fn(&I) -> T,
+    input: &I,
+    bench: Option<BenchOptions>,
+) -> PartReport {
+    let mut report = measure_part(day, part, || solver(input));
+
+    if let Some(options) = bench.filter(|_| !report.is_failed()) {
+        let stats = bench::run(options, &solver, input);
+        report.duration = stats.median;
+        report.bench = Some(stats);
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts the lines of the input, which must all be numbers, and picks the third one.
+    struct Count;
+
+    impl Solution for Count {
+        type Parsed = Vec<u32>;
+        type Error = std::num::ParseIntError;
+        type PartOne = Option<usize>;
+        type PartTwo = Option<usize>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+            input.lines().map(|line| line.parse()).collect()
+        }
+
+        fn part_one(numbers: &Self::Parsed) -> Option<usize> {
+            Some(numbers.len())
+        }
+
+        fn part_two(numbers: &Self::Parsed) -> Option<usize> {
+            Some(numbers[2] as usize)
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let run = run::<Count>(1, "1\n2\n7\n", None, None);
+        assert_eq!(run.parts.len(), 2);
+        assert_eq!(run.parts[0].answer.as_deref(), Some("3"));
+        assert_eq!(run.parts[1].answer.as_deref(), Some("7"));
+        assert_eq!(run.panic, None);
+    }
+
+    #[test]
+    fn test_run_keeps_parts_before_panic() {
+        let run = run::<Count>(1, "1\n2\n", None, None);
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].answer.as_deref(), Some("2"));
+        assert!(run.panic.unwrap().contains("index out of bounds"));
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        let run = run::<Count>(1, "1\nx\n", Some(2), None);
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].part, 2);
+        assert_eq!(
+            run.parts[0].error.as_deref(),
+            Some("could not parse input: invalid digit found in string")
+        );
+    }
+}
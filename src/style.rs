@@ -0,0 +1,178 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::io::{self, IsTerminal};
+
+use once_cell::sync::Lazy;
+
+/// Disables styling when set to a non-empty value, see <https://no-color.org>.
+pub const NO_COLOR_ENV: &str = "NO_COLOR";
+
+/// Raw escape sequences. Apply the styles with the functions below, which leave them out where
+/// output is not styled.
+pub const ANSI_ITALIC: &str = "\x1b[3m";
+pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_INVERSE: &str = "\x1b[7m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// Moves the cursor to the top left corner and clears the terminal.
+pub const ANSI_CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// Switches to the alternate screen and hides the cursor, and back.
+pub const ANSI_ENTER_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+pub const ANSI_LEAVE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+
+/// When to style output, selected with `--color <when>` or `--color=<when>`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    Always,
+    Never,
+    /// Style output if stdout is a terminal and `NO_COLOR` is not set.
+    #[default]
+    Auto,
+}
+
+impl ColorChoice {
+    pub fn parse(when: &str) -> Result<Self, String> {
+        match when.trim() {
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            "auto" => Ok(ColorChoice::Auto),
+            _ => Err(format!(
+                "invalid color choice \"{}\", expected always, never or auto",
+                when
+            )),
+        }
+    }
+
+    /// Reads `--color` from the process arguments, falling back to `auto` if it is missing or invalid.
+    pub fn from_env() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let when = args
+            .iter()
+            .enumerate()
+            .find_map(|(i, arg)| match arg.as_str() {
+                "--color" => args.get(i + 1).map(String::as_str),
+                arg => arg.strip_prefix("--color="),
+            });
+
+        match when.map(ColorChoice::parse) {
+            Some(Ok(choice)) => choice,
+            Some(Err(e)) => {
+                eprintln!("{}", e);
+                ColorChoice::Auto
+            }
+            None => ColorChoice::Auto,
+        }
+    }
+
+    pub fn use_color(self, is_terminal: bool, no_color: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => is_terminal && !no_color,
+        }
+    }
+}
+
+/// Takes `--color` out of `args`, since it is read by [`ColorChoice::from_env`] instead.
+/// Otherwise binaries with free arguments would mistake it or its value for one of them.
+pub fn remove_color_arg(args: &mut pico_args::Arguments) -> Result<(), pico_args::Error> {
+    args.opt_value_from_fn("--color", ColorChoice::parse)
+        .map(|_| ())
+}
+
+static ENABLED: Lazy<bool> = Lazy::new(|| {
+    let no_color = env::var_os(NO_COLOR_ENV).is_some_and(|value| !value.is_empty());
+    ColorChoice::from_env().use_color(io::stdout().is_terminal(), no_color)
+});
+
+/// Whether output of this process is styled.
+pub fn enabled() -> bool {
+    *ENABLED
+}
+
+/// A value that is displayed with an ANSI style if styling is [`enabled`].
+pub struct Styled<T> {
+    style: &'static str,
+    value: T,
+}
+
+impl<T: Display> Display for Styled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if enabled() {
+            write!(f, "{}{}{}", self.style, self.value, ANSI_RESET)
+        } else {
+            write!(f, "{}", self.value)
+        }
+    }
+}
+
+pub fn bold<T: Display>(value: T) -> Styled<T> {
+    Styled {
+        style: ANSI_BOLD,
+        value,
+    }
+}
+
+pub fn italic<T: Display>(value: T) -> Styled<T> {
+    Styled {
+        style: ANSI_ITALIC,
+        value,
+    }
+}
+
+/// Swaps foreground and background, e.g. to highlight a selection.
+pub fn inverse<T: Display>(value: T) -> Styled<T> {
+    Styled {
+        style: ANSI_INVERSE,
+        value,
+    }
+}
+
+pub fn red<T: Display>(value: T) -> Styled<T> {
+    Styled {
+        style: ANSI_RED,
+        value,
+    }
+}
+
+pub fn green<T: Display>(value: T) -> Styled<T> {
+    Styled {
+        style: ANSI_GREEN,
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color_choice() {
+        assert_eq!(ColorChoice::parse("never"), Ok(ColorChoice::Never));
+        assert!(ColorChoice::parse("sometimes").is_err());
+    }
+
+    #[test]
+    fn test_use_color() {
+        assert!(ColorChoice::Auto.use_color(true, false));
+        assert!(!ColorChoice::Auto.use_color(false, false));
+        assert!(!ColorChoice::Auto.use_color(true, true));
+        assert!(ColorChoice::Always.use_color(false, true));
+        assert!(!ColorChoice::Never.use_color(true, false));
+    }
+
+    #[test]
+    fn test_remove_color_arg() {
+        let mut args =
+            pico_args::Arguments::from_vec(["--color", "never", "7"].map(Into::into).to_vec());
+        remove_color_arg(&mut args).unwrap();
+        assert_eq!(args.free_from_str::<u8>().unwrap(), 7);
+    }
+}
@@ -0,0 +1,217 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::{fs, thread};
+
+use once_cell::sync::Lazy;
+
+use crate::style::ANSI_CLEAR;
+
+/// Whether frames are drawn, i.e. the `visualize` feature is enabled.
+/// Without it, [`frame`] does nothing and its render closure is never called.
+pub const ENABLED: bool = cfg!(feature = "visualize");
+
+/// Delay between terminal frames without `--frame-delay`.
+pub const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// Side length in pixels of one character cell in images.
+pub const IMAGE_SCALE: usize = 8;
+
+/// Receives the frames of a simulation. A frame is a grid of characters, one line per row,
+/// where spaces and `.` are empty cells.
+pub trait Visualizer {
+    fn frame(&mut self, frame: &str) -> io::Result<()>;
+}
+
+/// Draws frames over each other in the terminal and waits `delay` after each.
+pub struct Terminal {
+    pub delay: Duration,
+}
+
+impl Visualizer for Terminal {
+    fn frame(&mut self, frame: &str) -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+        write!(stdout, "{}{}", ANSI_CLEAR, frame)?;
+        stdout.flush()?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+/// Writes every frame as a numbered PPM image, `00001.ppm`, `00002.ppm`, ..., into `dir`.
+/// Convert them into an animation with e.g. `ffmpeg -i %05d.ppm rope.gif`.
+pub struct Images {
+    pub dir: PathBuf,
+    count: usize,
+}
+
+impl Images {
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Images { dir, count: 0 })
+    }
+}
+
+impl Visualizer for Images {
+    fn frame(&mut self, frame: &str) -> io::Result<()> {
+        self.count += 1;
+        let path = self.dir.join(format!("{:05}.ppm", self.count));
+        fs::write(path, to_ppm(frame, IMAGE_SCALE))
+    }
+}
+
+/// Color of a character cell: empty cells are white, `#` is black, letters get a color
+/// of their own, and everything else is gray.
+fn color(cell: char) -> [u8; 3] {
+    const PALETTE: [[u8; 3]; 8] = [
+        [230, 25, 75],
+        [60, 180, 75],
+        [255, 200, 25],
+        [0, 130, 200],
+        [245, 130, 48],
+        [145, 30, 180],
+        [70, 240, 240],
+        [240, 50, 230],
+    ];
+
+    match cell {
+        ' ' | '.' => [255, 255, 255],
+        '#' => [0, 0, 0],
+        c if c.is_ascii_alphabetic() => PALETTE[c.to_ascii_uppercase() as usize % PALETTE.len()],
+        _ => [128, 128, 128],
+    }
+}
+
+/// Renders a frame as a binary PPM image with `scale`×`scale` pixels per character.
+/// Short lines are padded with empty cells.
+pub fn to_ppm(frame: &str, scale: usize) -> Vec<u8> {
+    let rows: Vec<Vec<char>> = frame.lines().map(|line| line.chars().collect()).collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut image = format!("P6\n{} {}\n255\n", width * scale, rows.len() * scale).into_bytes();
+    for row in &rows {
+        let pixels: Vec<u8> = (0..width)
+            .flat_map(|x| {
+                let rgb = color(row.get(x).copied().unwrap_or(' '));
+                rgb.repeat(scale)
+            })
+            .collect();
+        for _ in 0..scale {
+            image.extend_from_slice(&pixels);
+        }
+    }
+    image
+}
+
+/// Where frames are drawn, selected on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// `--visualize [--frame-delay <ms>]` draws frames in the terminal.
+    Terminal(Duration),
+    /// `--visualize-dir <dir>` writes frames as images.
+    Images(PathBuf),
+}
+
+impl Target {
+    /// Takes `--visualize`, `--frame-delay` and `--visualize-dir` out of `args`.
+    /// Returns `None` if no frames are drawn, and an error if one of the values is invalid.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let dir: Option<PathBuf> = args.opt_value_from_str("--visualize-dir")?;
+        let terminal = args.contains("--visualize");
+        let delay_ms: Option<u64> = args.opt_value_from_str("--frame-delay")?;
+
+        Ok(match dir {
+            Some(dir) => Some(Target::Images(dir)),
+            None if terminal => Some(Target::Terminal(
+                delay_ms.map_or(DEFAULT_FRAME_DELAY, Duration::from_millis),
+            )),
+            None => None,
+        })
+    }
+
+    fn open(&self) -> Option<Box<dyn Visualizer + Send>> {
+        match self {
+            Target::Terminal(delay) => Some(Box::new(Terminal { delay: *delay })),
+            Target::Images(dir) => match Images::new(dir) {
+                Ok(images) => Some(Box::new(images)),
+                Err(e) => {
+                    eprintln!("Failed to create \"{}\": {}", dir.display(), e);
+                    None
+                }
+            },
+        }
+    }
+}
+
+// invalid arguments were already reported by `runner::run_day` before any part ran.
+static ACTIVE: Lazy<Option<Mutex<Box<dyn Visualizer + Send>>>> = Lazy::new(|| {
+    Target::from_args(&mut pico_args::Arguments::from_env())
+        .ok()
+        .flatten()
+        .and_then(|target| target.open())
+        .map(Mutex::new)
+});
+
+/// Emits a frame of a simulation to the visualizer selected on the command line.
+/// `render` is only called if there is one, so solutions can call this in their hot loops.
+#[inline]
+pub fn frame(render: impl FnOnce() -> String) {
+    if !ENABLED {
+        return;
+    }
+
+    if let Some(visualizer) = ACTIVE.as_ref() {
+        if let Err(e) = visualizer.lock().unwrap().frame(&render()) {
+            eprintln!("Failed to draw frame: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_from_args() {
+        let args = |args: &[&str]| {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            Target::from_args(&mut args)
+        };
+        assert_eq!(args(&[]).unwrap(), None);
+        assert_eq!(
+            args(&["--visualize", "--frame-delay", "5"]).unwrap(),
+            Some(Target::Terminal(Duration::from_millis(5)))
+        );
+        assert_eq!(
+            args(&["--visualize-dir", "frames"]).unwrap(),
+            Some(Target::Images("frames".into()))
+        );
+        assert!(args(&["--visualize", "--frame-delay", "fast"]).is_err());
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let image = to_ppm("#.\nA", 2);
+        let (header, pixels) = image.split_at(b"P6\n4 4\n255\n".len());
+        assert_eq!(header, b"P6\n4 4\n255\n");
+
+        let (black, white, a) = ([0; 3], [255; 3], color('A'));
+        let row = |left: [u8; 3], right: [u8; 3]| [left, left, right, right].concat();
+        assert_eq!(
+            pixels,
+            [
+                row(black, white),
+                row(black, white),
+                row(a, white),
+                row(a, white)
+            ]
+            .concat()
+        );
+    }
+}
@@ -0,0 +1,233 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Checks every registered day against its real input and the answers recorded in
+//! `src/<year>/answers.toml`. Inputs are not committed, so parts without an input or a recorded
+//! answer are reported as ignored instead of failing.
+//!
+//! Tests are named `y<year>::day<day>::part<part>` and accept the usual `cargo test` filters,
+//! e.g. `cargo test --test answers day07` or `cargo test --test answers -- --exact y2022::day07::part1`.
+//! This needs a custom harness because libtest decides which tests are ignored at compile time.
+use advent_of_code::answers::Answers;
+use advent_of_code::days::{self, Day};
+use advent_of_code::input;
+use std::collections::BTreeMap;
+use std::process;
+use std::time::Instant;
+
+/// Exit code of libtest when tests failed.
+const FAILURE_EXIT_CODE: i32 = 101;
+
+struct Args {
+    filters: Vec<String>,
+    skip: Vec<String>,
+    exact: bool,
+    list: bool,
+    /// Only run ignored tests.
+    ignored: bool,
+    include_ignored: bool,
+    /// `--format terse`, which lists tests without a summary.
+    terse: bool,
+}
+
+/// Reads the libtest arguments that apply to this suite and ignores the others.
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let format: Option<String> = args.opt_value_from_str("--format")?;
+    // tests run one after another and print nothing, so these do not change anything.
+    let _: Option<String> = args.opt_value_from_str("--test-threads")?;
+    let _: Option<String> = args.opt_value_from_str("--color")?;
+    for flag in ["--nocapture", "--show-output", "--quiet", "-q"] {
+        args.contains(flag);
+    }
+
+    Ok(Args {
+        skip: args.values_from_str("--skip")?,
+        exact: args.contains("--exact"),
+        list: args.contains("--list"),
+        ignored: args.contains("--ignored"),
+        include_ignored: args.contains("--include-ignored"),
+        terse: format.as_deref() == Some("terse"),
+        filters: args
+            .finish()
+            .into_iter()
+            .filter_map(|arg| arg.into_string().ok())
+            .filter(|arg| !arg.starts_with('-'))
+            .collect(),
+    })
+}
+
+/// One part of a registered day.
+struct Test {
+    name: String,
+    day: &'static Day,
+    part: u8,
+    input: Result<String, String>,
+    expected: Option<String>,
+}
+
+impl Test {
+    /// Why the test is skipped unless ignored tests are run.
+    fn ignore_reason(&self) -> Option<&str> {
+        match (&self.input, &self.expected) {
+            (Err(_), _) => Some("no input"),
+            (_, None) => Some("no recorded answer"),
+            _ => None,
+        }
+    }
+
+    fn run(&self) -> Result<(), String> {
+        let input = self.input.as_deref().map_err(Clone::clone)?;
+        let expected = self.expected.as_deref().ok_or(format!(
+            "no answer recorded in {}",
+            Answers::path(self.day.year).display()
+        ))?;
+
+        let run = self.day.run(input, Some(self.part), None);
+        if run.panic.is_some() {
+            // the panic hook already prints the message.
+            return Err("panicked".into());
+        }
+        let report = run
+            .parts
+            .into_iter()
+            .find(|report| report.part == self.part)
+            .ok_or("the part did not run")?;
+
+        match (report.answer, report.error) {
+            (_, Some(error)) => Err(format!("failed: {}", error)),
+            (Some(answer), None) if answer == expected => Ok(()),
+            (Some(answer), None) => Err(format!("expected {}, got {}", expected, answer)),
+            (None, None) => Err(format!("expected {}, but the part is not solved", expected)),
+        }
+    }
+}
+
+fn collect_tests() -> Vec<Test> {
+    let mut answers: BTreeMap<u16, Answers> = BTreeMap::new();
+    let mut tests = vec![];
+
+    for day in days::DAYS {
+        let answers = answers.entry(day.year).or_insert_with(|| {
+            Answers::load(day.year).unwrap_or_else(|e| {
+                eprintln!(
+                    "Failed to load {}: {}",
+                    Answers::path(day.year).display(),
+                    e
+                );
+                process::exit(FAILURE_EXIT_CODE);
+            })
+        });
+        let input = input::load("inputs", day.year, day.day).map_err(|e| e.to_string());
+
+        for part in 1..=2 {
+            tests.push(Test {
+                name: format!("y{}::day{:02}::part{}", day.year, day.day, part),
+                day,
+                part,
+                input: input.clone(),
+                expected: answers.get(day.day, part).map(String::from),
+            });
+        }
+    }
+
+    tests
+}
+
+fn matches(name: &str, pattern: &str, exact: bool) -> bool {
+    if exact {
+        name == pattern
+    } else {
+        name.contains(pattern)
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(FAILURE_EXIT_CODE);
+        }
+    };
+
+    let tests = collect_tests();
+    let total = tests.len();
+    let tests: Vec<Test> = tests
+        .into_iter()
+        .filter(|test| {
+            (args.filters.is_empty()
+                || args
+                    .filters
+                    .iter()
+                    .any(|filter| matches(&test.name, filter, args.exact)))
+                && !args
+                    .skip
+                    .iter()
+                    .any(|skip| matches(&test.name, skip, args.exact))
+                && (!args.ignored || test.ignore_reason().is_some())
+        })
+        .collect();
+    let filtered_out = total - tests.len();
+
+    if args.list {
+        for test in &tests {
+            println!("{}: test", test.name);
+        }
+        if !args.terse {
+            println!("\n{} tests, 0 benchmarks", tests.len());
+        }
+        return;
+    }
+
+    let timer = Instant::now();
+    let (mut passed, mut ignored, mut failures) = (0, 0, vec![]);
+    println!("\nrunning {} tests", tests.len());
+
+    for test in &tests {
+        match test.ignore_reason() {
+            Some(reason) if !args.ignored && !args.include_ignored => {
+                println!("test {} ... ignored, {}", test.name, reason);
+                ignored += 1;
+            }
+            _ => match test.run() {
+                Ok(()) => {
+                    println!("test {} ... ok", test.name);
+                    passed += 1;
+                }
+                Err(message) => {
+                    println!("test {} ... FAILED", test.name);
+                    failures.push((&test.name, message));
+                }
+            },
+        }
+    }
+
+    if !failures.is_empty() {
+        println!("\nfailures:\n");
+        for (name, message) in &failures {
+            println!("---- {} ----\n{}\n", name, message);
+        }
+        println!("failures:");
+        for (name, _) in &failures {
+            println!("    {}", name);
+        }
+    }
+
+    println!(
+        "\ntest result: {}. {} passed; {} failed; {} ignored; 0 measured; {} filtered out; finished in {:.2}s\n",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        passed,
+        failures.len(),
+        ignored,
+        filtered_out,
+        timer.elapsed().as_secs_f64()
+    );
+
+    if !failures.is_empty() {
+        process::exit(FAILURE_EXIT_CODE);
+    }
+}